@@ -37,9 +37,19 @@ typedef struct FvSeamParamsPartsV0 FvSeamParamsPartsV0;
 union FvSeamParamsV0;
 typedef union FvSeamParamsV0 FvSeamParamsV0;
 
+struct FvSeamParamsExtV1;
+typedef struct FvSeamParamsExtV1 FvSeamParamsExtV1;
+
+struct FvSeamParamsV1;
+typedef struct FvSeamParamsV1 FvSeamParamsV1;
+
 struct FvSeamProfile;
 typedef struct FvSeamProfile FvSeamProfile;
 
+/// 一个代表配置管理器句柄的不透明类型。
+struct FvSeamProfileManager;
+typedef struct FvSeamProfileManager FvSeamProfileManager;
+
 #define FV_SPA_V0_XP_NUM 30
 #define FV_SPA_V0_KP_NUM 30
 #define FV_SPA_V0_OP_NUM 60
@@ -47,6 +57,14 @@ typedef struct FvSeamProfile FvSeamProfile;
 #define FV_SPA_V0_OC_NUM 60
 #define FV_SPA_V0_SF_NUM 10
 
+#define FV_SPA_V1_XP_NUM 60
+#define FV_SPA_V1_KP_NUM 60
+#define FV_SPA_V1_OP_NUM 120
+#define FV_SPA_V1_VP_NUM 120
+#define FV_SPA_V1_OC_NUM 120
+#define FV_SPA_V1_SF_NUM 20
+#define FV_SPA_V1_AP_NUM 100
+
 /// 一个代表接头识别参数平面空间编号的枚举。
 typedef enum FvSeamParamFlatId
 {
@@ -598,13 +616,87 @@ typedef enum FvSeamParamSfId
 extern "C" {
 #endif // __cplusplus
 
+/// 以下函数均可在任意线程中调用，包括 Rust 异步运行时的工作线程。
+
+/// 以指定目录初始化全局配置管理器，必须在首次使用全局配置管理器之前调用。
+/// 未初始化时全局配置管理器使用默认目录。
+/// @param backup_dir 备份目录。
+/// @param config_dir 配置目录。
+/// @return `0` = 成功，`-1` = 失败或已经初始化。
+int32_t fv_spm_init_global(const char* backup_dir, const char* config_dir);
+
+/// 返回全局配置管理器的句柄，此句柄无需关闭。
+/// @note 不带句柄的 `fv_spm_*` 函数均作用于全局配置管理器。
+FvSeamProfileManager* fv_spm_global(void);
+
+/// 以指定名称及目录创建并注册配置管理器实例。
+/// @param name 实例名称，如传感器编号。
+/// @param backup_dir 备份目录。
+/// @param config_dir 配置目录。
+/// @return 句柄，使用完毕后必须调用 fv_spm_close() 关闭；失败时返回 `NULL`。
+FvSeamProfileManager* fv_spm_create(const char* name, const char* backup_dir,
+                                    const char* config_dir);
+
+/// 打开指定名称的配置管理器实例。
+/// @param name 实例名称。
+/// @return 句柄，使用完毕后必须调用 fv_spm_close() 关闭；实例不存在时返回 `NULL`。
+FvSeamProfileManager* fv_spm_open(const char* name);
+
+/// 关闭配置管理器句柄。
+/// @param spm 句柄，可以为 `NULL`。
+void fv_spm_close(FvSeamProfileManager* spm);
+
+/// 注销指定名称的配置管理器实例，已打开的句柄仍然有效。
+/// @param name 实例名称。
+/// @return `0` = 成功，`-1` = 实例不存在。
+int32_t fv_spm_remove(const char* name);
+
 /// 返回当前生效的接头识别参数配置。
 /// @note 切勿缓存返回的指针，此指针会在切换配置时发生改变。
+/// @note 读写时不受管理器锁保护，多线程环境下请使用 fv_spm_acquire_cur_profile()
+///       或 fv_spm_read_cur_v0()。
+/// @note 通过返回的指针修改参数后，请调用 fv_spm_publish_cur() 发布。
 FvSeamProfile* fv_spm_cur_profile(void);
 
+/// 返回当前生效配置的快照，快照与管理器中的配置互不影响，可在任意线程中读写。
+/// @return 快照指针，使用完毕后必须调用 fv_spm_release() 释放。
+FvSeamProfile* fv_spm_acquire_cur_profile(void);
+
+/// 释放由 fv_spm_acquire_cur_profile() 返回的配置快照。
+/// @param spr 快照指针，可以为 `NULL`。
+void fv_spm_release(FvSeamProfile* spr);
+
+/// 以无锁方式复制当前生效的 V0 版参数表，适用于实时跟踪循环。
+/// @param dst 目标参数表。
+/// @return 配置编号，失败时返回 `-1`。
+int32_t fv_spm_read_cur_v0(FvSeamParamsV0* dst);
+
+/// 以无锁方式复制当前生效的 V1 版参数表。
+/// @param dst 目标参数表。
+/// @return 配置编号，失败时返回 `-1`。
+int32_t fv_spm_read_cur_v1(FvSeamParamsV1* dst);
+
+/// 返回当前生效参数表的发布代号，参数表变化或切换配置后代号增加。
+/// 实时跟踪循环可先比较代号，有变化时再调用 fv_spm_read_cur_v0() 复制参数表。
+uint64_t fv_spm_cur_generation(void);
+
+/// 通过 fv_spm_cur_profile() 等返回的指针直接修改当前配置后，重新发布参数表。
+/// 其他 fv_spm_* 函数锁定管理器时也会检查并发布这类修改。
+/// @return 发布代号。
+uint64_t fv_spm_publish_cur(void);
+
 /// 返回当前生效的接头识别参数配置编号。
 int32_t fv_spm_cur_profile_id(void);
 
+/// 返回指定编号的接头识别参数配置。
+/// @param id - 配置编号。
+/// @return 配置指针，编号无效时返回 `NULL`。
+/// @note 返回的指针指向管理器内部的配置，在管理器的生命周期内保持有效，
+///       但读写时不受管理器锁保护，与其他线程对同一配置的修改或重新加载存在数据竞争；
+///       多线程环境下请使用 fv_spm_acquire_cur_profile() 或 fv_spm_read_cur_v0()。
+/// @note 通过返回的指针修改当前生效的配置后，请调用 fv_spm_publish_cur() 发布。
+FvSeamProfile* fv_spm_profile(int32_t id);
+
 /// 从文件中加载指定编号的接头识别参数配置。
 /// @param id - 配置编号。
 /// @return `0` = 成功，`-1` = 失败。
@@ -616,20 +708,58 @@ int32_t fv_spm_load_profile(int32_t id);
 int32_t fv_spm_switch_profile(int32_t id);
 
 /// 设置当前生效的接头识别参数配置（以复制方式）。
+/// 仅复制 V0 版参数表，扩展参数表、配置编号、名称及启用状态保持不变。
 /// @param spr 接头识别参数配置指针。
-void fv_spm_fill_cur_profile(const FvSeamProfile* spr);
+/// @return `0` = 成功，`-1` = 失败。
+int32_t fv_spm_fill_cur_profile(const FvSeamProfile* spr);
+
+/// 以复制方式设置当前生效的 V1 版参数表，配置编号、名称及启用状态保持不变。
+/// @param spa V1 版参数表指针。
+/// @return `0` = 成功，`-1` = 失败。
+int32_t fv_spm_fill_cur_profile_v1(const FvSeamParamsV1* spa);
 
 /// 设置当前生效的接头识别参数配置（以非复制方式）。
-/// @param spr 接头识别参数配置指针。
-void fv_spm_set_cur_profile_ptr(FvSeamProfile* spr);
+/// @param spr 接头识别参数配置指针，必须由管理器分配，@see fv_spm_profile()。
+/// @return `0` = 成功，`-1` = 失败。
+/// @warning 语义变更：早期版本声明为直接使用调用方持有的配置，但从未实现（始终失败）。
+///          现在仅接受管理器分配的配置，效果等同于以其编号调用 fv_spm_switch_profile()；
+///          调用方自行分配的配置返回 `-1`，请改用 fv_spm_fill_cur_profile() 复制。
+int32_t fv_spm_set_cur_profile_ptr(FvSeamProfile* spr);
+
+/// 以下 `fv_spmh_*` 函数与同名的 `fv_spm_*` 函数相同，但作用于指定的配置管理器。
+/// 句柄为 `NULL` 时返回 `NULL`、`-1` 或 `0`。
+/// @param spm 配置管理器句柄，@see fv_spm_global(), fv_spm_create(), fv_spm_open()。
+FvSeamProfile* fv_spmh_cur_profile(FvSeamProfileManager* spm);
+FvSeamProfile* fv_spmh_acquire_cur_profile(FvSeamProfileManager* spm);
+int32_t fv_spmh_read_cur_v0(FvSeamProfileManager* spm, FvSeamParamsV0* dst);
+int32_t fv_spmh_read_cur_v1(FvSeamProfileManager* spm, FvSeamParamsV1* dst);
+uint64_t fv_spmh_cur_generation(FvSeamProfileManager* spm);
+uint64_t fv_spmh_publish_cur(FvSeamProfileManager* spm);
+int32_t fv_spmh_cur_profile_id(FvSeamProfileManager* spm);
+FvSeamProfile* fv_spmh_profile(FvSeamProfileManager* spm, int32_t id);
+int32_t fv_spmh_load_profile(FvSeamProfileManager* spm, int32_t id);
+int32_t fv_spmh_switch_profile(FvSeamProfileManager* spm, int32_t id);
+int32_t fv_spmh_fill_cur_profile(FvSeamProfileManager* spm, const FvSeamProfile* spr);
+int32_t fv_spmh_fill_cur_profile_v1(FvSeamProfileManager* spm, const FvSeamParamsV1* spa);
+int32_t fv_spmh_set_cur_profile_ptr(FvSeamProfileManager* spm, FvSeamProfile* spr);
 
 /// 返回 FvSeamProfile 中的 FvSeamParamsV0 参数。
 /// @param spr 接头识别参数配置指针，@see fv_spm_cur_profile()。
 FvSeamParamsV0* fv_spr_v0(FvSeamProfile* spr);
 
 /// 返回当前生效的 FvSeamParamsV0 参数。
+/// @note 读写时不受管理器锁保护，@see fv_spm_read_cur_v0()。
 FvSeamParamsV0* fv_spa_v0_cur(void);
 
+/// 返回 FvSeamProfile 中的 FvSeamParamsV1 参数。
+/// @param spr 接头识别参数配置指针，必须由管理器分配，@see fv_spm_cur_profile(),
+///            fv_spm_acquire_cur_profile()。
+/// @note V1 版参数表的前半部分即为 V0 版参数表，返回值与 `spr->v0` 地址相同。
+FvSeamParamsV1* fv_spr_v1(FvSeamProfile* spr);
+
+/// 返回当前生效的 FvSeamParamsV1 参数。
+FvSeamParamsV1* fv_spa_v1_cur(void);
+
 /// 返回 FvSeamParamsV0 平面空间中指定寄存器的 32 位浮点参数值。
 /// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
 /// @param index 寄存器编号，@see FvSeamParamFlatId。
@@ -745,6 +875,36 @@ int32_t fv_spa_v0_vp_en(FvSeamParamsV0* spa, FvSeamParamVpId index);
 /// @param index 寄存器编号，@see FvSeamParamOcId。
 int32_t fv_spa_v0_oc_en(FvSeamParamsV0* spa, FvSeamParamOcId index);
 
+/// 设置 FvSeamParamsV0 中的指定索引的 XP 参数开关。
+/// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
+/// @param index 寄存器编号，@see FvSeamParamXpId。
+/// @param en 非 0 表示使能。
+void fv_spa_v0_set_xp_en(FvSeamParamsV0* spa, FvSeamParamXpId index, int32_t en);
+
+/// 设置 FvSeamParamsV0 中的指定索引的 KP 参数开关。
+/// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
+/// @param index 寄存器编号，@see FvSeamParamKpId。
+/// @param en 非 0 表示使能。
+void fv_spa_v0_set_kp_en(FvSeamParamsV0* spa, FvSeamParamKpId index, int32_t en);
+
+/// 设置 FvSeamParamsV0 中的指定索引的 OP 参数开关。
+/// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
+/// @param index 寄存器编号，@see FvSeamParamOpId。
+/// @param en 非 0 表示使能。
+void fv_spa_v0_set_op_en(FvSeamParamsV0* spa, FvSeamParamOpId index, int32_t en);
+
+/// 设置 FvSeamParamsV0 中的指定索引的 VP 参数开关。
+/// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
+/// @param index 寄存器编号，@see FvSeamParamVpId。
+/// @param en 非 0 表示使能。
+void fv_spa_v0_set_vp_en(FvSeamParamsV0* spa, FvSeamParamVpId index, int32_t en);
+
+/// 设置 FvSeamParamsV0 中的指定索引的 OC 参数开关。
+/// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
+/// @param index 寄存器编号，@see FvSeamParamOcId。
+/// @param en 非 0 表示使能。
+void fv_spa_v0_set_oc_en(FvSeamParamsV0* spa, FvSeamParamOcId index, int32_t en);
+
 /// 返回 FvSeamParamsV0 中的主要接头形式值。
 /// @param spa 接头识别参数 V0 指针，@see fv_spr_v0(), fv_spa_v0_cur()。
 int32_t fv_spa_v0_jtma(FvSeamParamsV0* spa);
@@ -1195,6 +1355,39 @@ union FvSeamParamsV0
 #endif // __cplusplus
 };
 
+/// 一个代表 V1 版扩展参数分区表的类型。
+/// 原有分区在 V0 版参数之后各扩展一倍，另新增 AP 分区。
+struct FvSeamParamsExtV1
+{
+    FvSeamParamValue xp[FV_SPA_V1_XP_NUM - FV_SPA_V0_XP_NUM];
+    FvSeamParamValue kp[FV_SPA_V1_KP_NUM - FV_SPA_V0_KP_NUM];
+    FvSeamParamValue op[FV_SPA_V1_OP_NUM - FV_SPA_V0_OP_NUM];
+    FvSeamParamValue vp[FV_SPA_V1_VP_NUM - FV_SPA_V0_VP_NUM];
+    FvSeamParamValue oc[FV_SPA_V1_OC_NUM - FV_SPA_V0_OC_NUM];
+    /// 最后一项记录参数表布局版本。
+    FvSeamParamValue sf[FV_SPA_V1_SF_NUM - FV_SPA_V0_SF_NUM];
+    /// 新算法参数。
+    FvSeamParamValue ap[FV_SPA_V1_AP_NUM];
+};
+
+/// 一个代表 V1 版接头识别参数表的类型。
+/// 前半部分与 V0 版参数表布局相同。
+struct FvSeamParamsV1
+{
+    /// V0 版参数表。
+    FvSeamParamsV0 v0;
+    /// V1 版扩展参数表。
+    FvSeamParamsExtV1 ext;
+
+#ifdef __cplusplus
+    /// 返回参数表布局版本，记录在扩展 SF 分区的最后一项。
+    int32_t version() const
+    {
+        return ext.sf[FV_SPA_V1_SF_NUM - FV_SPA_V0_SF_NUM - 1].i32;
+    }
+#endif // __cplusplus
+};
+
 /// 一个代表接头识别参数配置的类型。
 struct FvSeamProfile
 {
@@ -1206,7 +1399,7 @@ struct FvSeamProfile
     void* meta;
     /// V0 版参数表指针。
     FvSeamParamsV0* v0;
-    // TODO：后续扩展参数以 v1, v2 形式增加。
+    // 为保持二进制兼容不再增加字段，V1 版参数表通过 fv_spr_v1() 获取。
 
 #ifdef __cplusplus
     FvSeamProfile* current()
@@ -1,21 +1,70 @@
-use super::FileDigest;
+use super::{write_atomic, FileDigest};
 use atomic_instant::AtomicInstant;
-use log::{debug, error, info, warn};
+use log::{debug, error, warn};
 use serde::de::Deserializer;
 use serde::ser::{SerializeStruct, Serializer};
 use serde::{Deserialize, Serialize};
-use std::ffi::c_void;
+use std::ffi::{c_char, c_void, CStr};
 use std::fmt::{self, Debug};
-use std::io;
 use std::io::Write;
-use std::path::Path;
-use std::process::Command;
-use std::sync::atomic::{AtomicUsize, Ordering};
-#[cfg(not(feature = "async"))]
-use std::sync::Mutex;
-use std::sync::{Arc, LazyLock};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+mod archive;
+#[cfg(feature = "async")]
+mod async_io;
+mod backup;
+mod diff;
+mod en_bits;
+mod error;
+mod events;
+mod flush;
+mod joint_type;
+mod lock;
+mod meta_sync;
+mod named;
+mod param_desc;
+mod patch;
+mod publish;
+mod recovery;
+mod registry;
+mod schema;
+mod slots;
+mod templates;
+mod v1;
+mod validation;
+mod watch;
+mod worker;
+
+pub use backup::{SeamBackupEntry, SeamBackupRetention};
+pub use diff::{SeamExtParamChange, SeamMergeConflict, SeamParamChange, SeamProfileDiff};
+pub use en_bits::SeamParamEnBits;
+pub use error::SeamProfileError;
+use events::SeamProfileNotifier;
+pub use events::{SeamProfileCallback, SeamProfileEvent, SeamProfileSubscription};
+pub use joint_type::JointType;
+pub use lock::{SeamProfileGuard, SeamProfileLockFuture, SeamProfileManagerLock};
+pub use named::{SeamParamsFormat, SeamParamsV0Named};
+pub use param_desc::{
+    seam_param_descs, SeamParamArea, SeamParamChoice, SeamParamDesc, SeamParamType,
+    SeamParamTypedValue,
+};
+pub use patch::{SeamJsonPatchOp, SeamProfilePatch};
+pub use publish::{SeamParamsPublisher, SeamParamsSnapshot};
+pub use recovery::SeamProfileLoadReport;
+pub use registry::SeamProfileManagerHandle;
+pub use schema::{write_json_schemas, SeamSchemaKind};
+pub use slots::{SeamProfileManagerOptions, DEFAULT_PROFILE_SLOTS};
+pub use templates::{seam_profile_template, seam_profile_templates, SeamProfileTemplate};
+pub use v1::{SeamParamsExtV1, SeamParamsMigration, SeamParamsV1};
+pub use validation::{SeamParamViolation, SeamValidateOptions};
+#[cfg(target_os = "linux")]
+pub use watch::SeamProfileWatcher;
+pub use watch::{SeamExternalChange, SeamProfileWatcherOptions, SeamReloadPolicy};
 #[cfg(feature = "async")]
-use tokio::sync::Mutex;
+pub use worker::SeamProfileAsyncWorker;
+pub use worker::{SeamProfileWorker, SeamProfileWorkerOptions};
 
 pub const SEAM_PROFILE_SCHEMA: &str = "https://full-v.com/schemas/seam-profile.json";
 pub const SEAM_PROFILES_SCHEMA: &str = "https://full-v.com/schemas/seam-profiles.json";
@@ -98,6 +147,8 @@ pub enum SeamParamFlatId {
     OpLayerSelect,
     OpGapPos,
     OpGapType,
+    OpTrackPos,
+    OpTrackType,
     OpBevelAngle1,
     OpBevelAngle2,
     OpNormalType1,
@@ -150,8 +201,6 @@ pub enum SeamParamFlatId {
     Op55,
     Op56,
     Op57,
-    Op58,
-    Op59,
     // VP 区
     VpAngleMin = 120,
     VpAngleMax,
@@ -453,6 +502,8 @@ pub enum SeamParamOpId {
     LayerSelect,
     GapPos,
     GapType,
+    TrackPos,
+    TrackType,
     BevelAngle1,
     BevelAngle2,
     NormalType1,
@@ -505,8 +556,6 @@ pub enum SeamParamOpId {
     Op55,
     Op56,
     Op57,
-    Op58,
-    Op59,
     //
     Invalid = 0xffff,
 }
@@ -769,23 +818,6 @@ impl SeamParamSfId {
     }
 }
 
-pub enum SeamProfileError {
-    Io(std::io::Error),
-    Json(serde_json::Error),
-}
-
-impl From<std::io::Error> for SeamProfileError {
-    fn from(value: std::io::Error) -> Self {
-        Self::Io(value)
-    }
-}
-
-impl From<serde_json::Error> for SeamProfileError {
-    fn from(value: serde_json::Error) -> Self {
-        Self::Json(value)
-    }
-}
-
 /// 一个代表接头识别参数值的类型。
 #[derive(Copy, Clone)]
 #[repr(C)]
@@ -978,6 +1010,8 @@ impl<'de> Deserialize<'de> for SeamParamsV0 {
     {
         use serde::de::{Error, MapAccess, Visitor};
 
+        const FIELDS: &[&str] = &["values", "xp", "kp", "op", "vp", "oc", "sf", "en"];
+
         struct MyVisitor;
 
         impl<'de> Visitor<'de> for MyVisitor {
@@ -992,27 +1026,39 @@ impl<'de> Deserialize<'de> for SeamParamsV0 {
                 V: MapAccess<'de>,
             {
                 let mut values = None;
-                while let Some(key) = map.next_key::<&str>()? {
-                    match key {
-                        "values" => {
-                            values = map.next_value::<Vec<i32>>().ok();
-                        }
-                        _ => {
-                            return Err(Error::unknown_field(key, &["parts", "values"]));
-                        }
+                let mut areas = Vec::new();
+                let mut en = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "values" {
+                        values = Some(map.next_value::<Vec<i32>>()?);
+                    } else if key == "en" {
+                        en = Some(map.next_value::<serde_json::Map<_, _>>()?);
+                    } else if let Some(area) = SeamParamArea::from_name(&key) {
+                        areas.push((area, map.next_value::<serde_json::Map<_, _>>()?));
+                    } else {
+                        return Err(Error::unknown_field(&key, FIELDS));
                     }
                 }
                 let mut v0 = SeamParamsV0::default();
                 if let Some(vals) = values {
-                    let n = vals.len().min(250);
+                    let n = vals.len();
+                    if n > 250 {
+                        return Err(Error::invalid_length(n, &"at most 250 values"));
+                    }
                     let dst: &mut [i32] = v0.as_mut();
-                    dst[..n].copy_from_slice(&vals[..n]);
+                    dst[..n].copy_from_slice(&vals);
+                }
+                for (area, params) in &areas {
+                    v0.read_named_area(*area, params).map_err(Error::custom)?;
+                }
+                if let Some(en) = &en {
+                    v0.read_named_en(en).map_err(Error::custom)?;
                 }
                 Ok(v0)
             }
         }
 
-        deserializer.deserialize_struct("SeamParamsV0", &["values"], MyVisitor)
+        deserializer.deserialize_struct("SeamParamsV0", FIELDS, MyVisitor)
     }
 }
 
@@ -1300,7 +1346,7 @@ impl SeamProfileMeta {
 }
 
 /// 一个代表接头识别参数配置的类型。
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[repr(C)]
 pub struct SeamProfile {
     /// 档案规范。
@@ -1312,24 +1358,36 @@ pub struct SeamProfile {
     pub id: i32,
     /// 元数据。
     pub meta: SeamProfileMeta,
-    /// V0 版参数表指针。
+    /// V0 版参数表。
     pub v0: SeamParamsV0,
-    // TODO：后续扩展参数以 v1, v2 形式增加。
+    /// V1 版扩展参数表，紧随 V0 版参数表存放，两者合为 V1 版参数表。
+    #[serde(rename = "v1", default)]
+    pub v1_ext: SeamParamsExtV1,
     /// 变更记录。
     #[serde(skip)]
     commits: AtomicUsize,
 }
 
+impl Default for SeamProfile {
+    fn default() -> Self {
+        Self::new(false, 0)
+    }
+}
+
 impl SeamProfile {
     pub fn new(enabled: bool, id: i32) -> Self {
-        Self {
+        let mut profile = Self {
             schema: SEAM_PROFILE_SCHEMA.into(),
             enabled,
             id,
             meta: Default::default(),
             v0: Default::default(),
+            v1_ext: Default::default(),
             commits: AtomicUsize::new(0),
-        }
+        };
+        let _ = profile.v1_mut().migrate();
+        profile.sync_meta();
+        profile
     }
 
     pub fn is_enabled(&self) -> bool {
@@ -1391,29 +1449,35 @@ impl SeamProfile {
         self.commit();
     }
 
+    /// 设置参数值，修改的是当前生效配置时需调用 [`SeamProfileManager::republish`] 发布。
     pub fn set_v0_value_f32(&mut self, index: SeamParamFlatId, value: f32) {
         self.v0.set_value_f32(index, value);
+        self.sync_meta();
         self.commit();
     }
 
+    /// 设置参数值，@see [`SeamProfile::set_v0_value_f32`]。
     pub fn set_v0_value_i32(&mut self, index: SeamParamFlatId, value: i32) {
         self.v0.set_value_i32(index, value);
+        self.sync_meta();
         self.commit();
     }
 
     pub fn merge(&mut self, other: &SeamProfile) {
         self.set_enabled(other.is_enabled());
         self.meta.set_name(other.name());
-        self.meta.set_joint_type(other.v0.joint_type());
         self.v0.merge(&other.v0);
+        self.v1_ext = other.v1_ext;
+        self.sync_meta();
         self.commit();
     }
 
     pub fn merge_with_commit(&mut self, other: &SeamProfile) {
         self.enabled = other.is_enabled();
         self.meta.set_name(other.name());
-        self.meta.set_joint_type(other.v0.joint_type());
         self.v0.merge(&other.v0);
+        self.v1_ext = other.v1_ext;
+        self.sync_meta();
     }
 
     /// 提交一次变更记录。
@@ -1445,9 +1509,8 @@ pub struct SeamProfileFFI {
     pub id: i32,
     /// 元数据。
     pub meta: *mut c_void,
-    /// V0 版参数表指针。
+    /// V0 版参数表指针，由管理器分配时其后紧接扩展参数表，@see [`fv_spr_v1`]。
     pub v0: *mut SeamParamsV0,
-    // TODO：后续扩展参数以 v1, v2 形式增加。
 }
 
 impl Default for SeamProfileFFI {
@@ -1471,6 +1534,8 @@ pub type FvSeamParamSfId = SeamParamSfId;
 pub type FvSeamParamValue = SeamParamValue;
 pub type FvSeamParamsPartsV0 = SeamParamsPartsV0;
 pub type FvSeamParamsV0 = SeamParamsV0;
+pub type FvSeamParamsExtV1 = SeamParamsExtV1;
+pub type FvSeamParamsV1 = SeamParamsV1;
 pub type FvSeamProfile = SeamProfileFFI;
 
 /// 一个代表接头识别配置管理器的类型。
@@ -1483,6 +1548,25 @@ pub struct SeamProfileManager {
     pub profile_switched: bool,
     pub profile_updated: bool,
     pub profiles_changed: bool,
+    /// 加载时是否拒绝未通过校验的配置。
+    pub reject_invalid: bool,
+    /// 配置校验选项。
+    pub validate_options: SeamValidateOptions,
+    /// 备份保留策略。
+    pub backup_retention: SeamBackupRetention,
+    /// 保存及导出时参数表的 JSON 形式。
+    pub params_format: SeamParamsFormat,
+    sparse: bool,
+    existing: Vec<bool>,
+    saved_hashes: Vec<AtomicU64>,
+    #[cfg(feature = "async")]
+    flush_lock: Arc<tokio::sync::Mutex<()>>,
+    #[cfg(not(feature = "async"))]
+    flush_lock: Arc<Mutex<()>>,
+    backup_lock: Arc<Mutex<()>>,
+    notifier: SeamProfileNotifier,
+    publisher: Arc<SeamParamsPublisher>,
+    staging_path: PathBuf,
     commits: AtomicUsize,
     flush_times: AtomicUsize,
     all_modified: AtomicInstant,
@@ -1492,22 +1576,60 @@ pub struct SeamProfileManager {
 unsafe impl Send for SeamProfileManager {}
 unsafe impl Sync for SeamProfileManager {}
 
-static SEAM_PROFILE_MANAGER: LazyLock<Arc<Mutex<SeamProfileManager>>> = LazyLock::new(|| {
-    Arc::new(Mutex::new(SeamProfileManager::new(
-        DEFAULT_BACKUP_DIR,
-        DEFAULT_CONFIG_DIR,
-    )))
-});
+static SEAM_PROFILE_MANAGER: OnceLock<Arc<SeamProfileManagerLock>> = OnceLock::new();
 
 impl SeamProfileManager {
+    /// 创建配置管理器，目录创建失败时仅记录错误。
     pub fn new<S: Into<String>>(backup_dir: S, config_dir: S) -> Self {
+        Self::with_options(backup_dir, config_dir, Default::default())
+    }
+
+    /// 以指定选项创建配置管理器，目录创建失败时仅记录错误。
+    pub fn with_options<S: Into<String>>(
+        backup_dir: S,
+        config_dir: S,
+        options: SeamProfileManagerOptions,
+    ) -> Self {
+        let backup_dir: String = backup_dir.into();
+        let config_dir: String = config_dir.into();
+        for dir in [&backup_dir, &config_dir] {
+            if let Err(err) = std::fs::create_dir_all(dir) {
+                error!("创建目录 {} 失败：{}", dir, err);
+            }
+        }
+        Self::with_dirs(backup_dir, config_dir, options)
+    }
+
+    /// 创建配置管理器，目录创建失败时返回错误。
+    pub fn try_new<S: Into<String>>(
+        backup_dir: S,
+        config_dir: S,
+    ) -> Result<Self, SeamProfileError> {
+        Self::try_with_options(backup_dir, config_dir, Default::default())
+    }
+
+    /// 以指定选项创建配置管理器，目录创建失败时返回错误。
+    pub fn try_with_options<S: Into<String>>(
+        backup_dir: S,
+        config_dir: S,
+        options: SeamProfileManagerOptions,
+    ) -> Result<Self, SeamProfileError> {
         let backup_dir: String = backup_dir.into();
         let config_dir: String = config_dir.into();
-        std::fs::create_dir_all(&backup_dir).unwrap();
-        std::fs::create_dir_all(&config_dir).unwrap();
-        let mut profiles = Vec::with_capacity(256);
-        let mut profiles_ffi = Vec::with_capacity(256);
-        for i in 0..256 {
+        std::fs::create_dir_all(&backup_dir)?;
+        std::fs::create_dir_all(&config_dir)?;
+        Ok(Self::with_dirs(backup_dir, config_dir, options))
+    }
+
+    fn with_dirs(
+        backup_dir: String,
+        config_dir: String,
+        options: SeamProfileManagerOptions,
+    ) -> Self {
+        let slots = options.slots.max(1);
+        let mut profiles = Vec::with_capacity(slots);
+        let mut profiles_ffi = Vec::with_capacity(slots);
+        for i in 0..slots as i32 {
             let mut profile = Box::new(SeamProfile::new(false, i));
             let profile_ffi = SeamProfileFFI {
                 enabled: 0,
@@ -1518,7 +1640,10 @@ impl SeamProfileManager {
             profiles.push(profile);
             profiles_ffi.push(profile_ffi);
         }
+        let publisher = Arc::new(SeamParamsPublisher::new());
+        publisher.publish(0, profiles[0].v1());
         Self {
+            staging_path: archive::staging_path(&backup_dir),
             backup_dir,
             config_dir,
             profiles,
@@ -1527,6 +1652,17 @@ impl SeamProfileManager {
             profile_switched: false,
             profile_updated: false,
             profiles_changed: false,
+            reject_invalid: false,
+            validate_options: Default::default(),
+            backup_retention: Default::default(),
+            params_format: Default::default(),
+            sparse: options.sparse,
+            existing: vec![!options.sparse; slots],
+            saved_hashes: (0..slots).map(|_| AtomicU64::new(0)).collect(),
+            flush_lock: Default::default(),
+            backup_lock: Default::default(),
+            notifier: SeamProfileNotifier::new(),
+            publisher,
             commits: AtomicUsize::new(0),
             flush_times: AtomicUsize::new(0),
             all_modified: AtomicInstant::now(),
@@ -1534,8 +1670,18 @@ impl SeamProfileManager {
         }
     }
 
-    pub fn global<'r>() -> &'r Mutex<Self> {
-        &SEAM_PROFILE_MANAGER
+    /// 返回全局配置管理器，未经 [`SeamProfileManager::init_global`] 初始化时使用默认目录。
+    pub fn global() -> &'static SeamProfileManagerLock {
+        Self::global_ref()
+    }
+
+    /// 返回全局配置管理器的共享引用，可用于启动 [`SeamProfileWorker`]。
+    pub fn global_arc() -> Arc<SeamProfileManagerLock> {
+        Self::global_ref().clone()
+    }
+
+    fn global_ref() -> &'static Arc<SeamProfileManagerLock> {
+        SEAM_PROFILE_MANAGER.get_or_init(|| Arc::new(SeamProfileManagerLock::new(Self::default())))
     }
 
     pub fn current_profile(&self) -> &SeamProfile {
@@ -1568,8 +1714,11 @@ impl SeamProfileManager {
 
     pub fn set_current_profile_id(&mut self, index: usize) {
         if index < self.profiles.len() && self.current_index != index {
+            let old = self.current_index;
             self.current_index = index;
             self.profile_switched = true;
+            self.publish_current();
+            self.emit(SeamProfileEvent::ProfileSwitched { old, new: index });
         }
     }
 
@@ -1596,8 +1745,7 @@ impl SeamProfileManager {
 
     pub fn disable_profile(&mut self, id: usize) {
         if id < self.profiles.len() {
-            self.profiles[id].set_enabled(false);
-            self.profiles_ffi[id].enabled = 0;
+            self.set_profile_enabled(id, false);
             self.profiles_modified.set_now();
             self.commit();
         }
@@ -1605,119 +1753,225 @@ impl SeamProfileManager {
 
     pub fn enable_profile(&mut self, id: usize) {
         if id < self.profiles.len() {
-            self.profiles[id].set_enabled(true);
-            self.profiles_ffi[id].enabled = 1;
+            self.set_profile_enabled(id, true);
             self.profiles_modified.set_now();
             self.commit();
         }
     }
 
     pub fn disable_all_profiles(&mut self) {
-        for p in &mut self.profiles {
-            p.set_enabled(false);
-        }
-        for p in &mut self.profiles_ffi {
-            p.enabled = 0;
+        for id in 0..self.profiles.len() {
+            self.set_profile_enabled(id, false);
         }
         self.profiles_modified.set_now();
         self.commit();
     }
 
     pub fn enable_all_profiles(&mut self) {
-        for p in &mut self.profiles {
-            p.set_enabled(true);
-        }
-        for p in &mut self.profiles_ffi {
-            p.enabled = 1;
+        for id in 0..self.profiles.len() {
+            self.set_profile_enabled(id, true);
         }
         self.profiles_modified.set_now();
         self.commit();
     }
 
+    fn set_profile_enabled(&mut self, id: usize, yes: bool) {
+        let changed = self.profiles[id].is_enabled() != yes;
+        self.profiles[id].set_enabled(yes);
+        self.profiles_ffi[id].enabled = yes as i32;
+        if changed {
+            self.emit(SeamProfileEvent::ProfileEnabled {
+                profile_id: id,
+                enabled: yes,
+            });
+        }
+    }
+
+    pub fn is_reject_invalid(&self) -> bool {
+        self.reject_invalid
+    }
+
+    /// 设置加载时是否拒绝未通过校验的配置，否则仅记录警告后合并。
+    pub fn set_reject_invalid(&mut self, yes: bool) {
+        self.reject_invalid = yes;
+    }
+
+    /// 按当前校验选项检查配置。
+    pub fn check_profile(&self, profile: &SeamProfile) -> Result<(), SeamProfileError> {
+        let violations = profile.validate_with(&self.validate_options);
+        if violations.is_empty() {
+            return Ok(());
+        }
+        for v in &violations {
+            warn!("配置 #{} 校验失败：{}", profile.id(), v);
+        }
+        if self.reject_invalid {
+            Err(SeamProfileError::Validation {
+                id: profile.id(),
+                violations,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn get_profile(&self, id: usize) -> &SeamProfile {
         &self.profiles[id]
     }
 
+    /// 返回指定编号的配置，编号无效时返回错误。
+    pub fn try_get_profile(&self, id: usize) -> Result<&SeamProfile, SeamProfileError> {
+        self.profiles
+            .get(id)
+            .map(|p| p.as_ref())
+            .ok_or(SeamProfileError::InvalidId(id as i32))
+    }
+
     pub fn get_profile_mut(&mut self, id: usize) -> &mut SeamProfile {
         &mut self.profiles[id]
     }
 
-    pub fn load_profile_from_json_str(&mut self, json: &str) -> serde_json::Result<()> {
-        let dst = serde_json::from_str::<SeamProfile>(json)?;
+    pub fn load_profile_from_json_str(&mut self, json: &str) -> Result<(), SeamProfileError> {
+        let mut dst = serde_json::from_str::<SeamProfile>(json)?;
+        dst.migrate()?;
         let n = self.profiles.len() as i32;
-        if dst.id >= 0 && dst.id < n {
-            let src = &mut self.profiles[dst.id as usize];
-            src.merge(&dst);
-            // let _r = self.save_profile(dst.id as usize);
-            self.profiles_modified.set_now();
-            self.commit();
-        }
+        if dst.id < 0 || dst.id >= n {
+            return Err(SeamProfileError::InvalidId(dst.id));
+        }
+        self.check_profile(&dst)?;
+        let src = &mut self.profiles[dst.id as usize];
+        src.merge(&dst);
+        // let _r = self.save_profile(dst.id as usize);
+        self.profiles_ffi[dst.id as usize].enabled = dst.enabled as i32;
+        self.existing[dst.id as usize] = true;
+        self.profiles_modified.set_now();
+        self.commit();
+        self.emit(SeamProfileEvent::ProfilesReloaded {
+            ids: vec![dst.id as usize],
+        });
         Ok(())
     }
 
-    pub fn load_profiles_from_json_str(&mut self, json: &str) -> serde_json::Result<()> {
-        let info = serde_json::from_str::<SeamProfilesInfo>(json)?;
+    pub fn load_profiles_from_json_str(&mut self, json: &str) -> Result<(), SeamProfileError> {
+        let mut info = serde_json::from_str::<SeamProfilesInfo>(json)?;
+        check_schema(&info.schema, SEAM_PROFILES_SCHEMA)?;
         let n = self.profiles.len() as i32;
-        for dst in &info.profiles {
-            if dst.id >= 0 && dst.id < n {
-                let src = &mut self.profiles[dst.id as usize];
-                src.merge(dst);
-                // let _r = self.save_profile(dst.id as usize);
-                self.profiles_modified.set_now();
-                self.commit();
+        for dst in &mut info.profiles {
+            dst.migrate()?;
+            if dst.id < 0 || dst.id >= n {
+                return Err(SeamProfileError::InvalidId(dst.id));
             }
+            self.check_profile(dst)?;
+        }
+        for dst in &info.profiles {
+            let src = &mut self.profiles[dst.id as usize];
+            src.merge(dst);
+            // let _r = self.save_profile(dst.id as usize);
+            self.profiles_ffi[dst.id as usize].enabled = dst.enabled as i32;
+            self.existing[dst.id as usize] = true;
+            self.profiles_modified.set_now();
+            self.commit();
+        }
+        if !info.profiles.is_empty() {
+            self.emit(SeamProfileEvent::ProfilesReloaded {
+                ids: info.profiles.iter().map(|p| p.id as usize).collect(),
+            });
         }
         Ok(())
     }
 
     pub fn load_profile(&mut self, id: usize) -> Result<SeamProfile, SeamProfileError> {
-        let path = format!("{}/seam-profile-{}.json", self.config_dir, id);
+        self.try_get_profile(id)?;
+        let path = self.profile_path(id);
         let text = std::fs::read_to_string(&path)?;
-        let profile = serde_json::from_str::<SeamProfile>(&text)?;
+        let profile = self.parse_profile(&text)?;
         debug!("配置 #{} 已经从 {} 加载", id, path);
         Ok(profile)
     }
 
-    pub fn load_all_profiles(&mut self) {
-        let n = self.profiles.len();
-        for i in 0..n {
-            if let Ok(profile) = self.load_profile(i) {
-                self.get_profile_mut(i).merge_with_commit(&profile);
-            } else {
-                warn!("配置 #{} 不存在，创建默认配置 ...", i);
-                let _r = self.save_profile(i);
-            }
-        }
+    /// 解析配置文件内容，并完成版本迁移及校验。
+    fn parse_profile(&self, text: &str) -> Result<SeamProfile, SeamProfileError> {
+        let mut profile = serde_json::from_str::<SeamProfile>(text)?;
+        profile.migrate()?;
+        self.check_profile(&profile)?;
+        Ok(profile)
+    }
+
+    /// 从文件重新加载指定编号的配置并替换内存中的配置。
+    pub fn reload_profile(&mut self, id: usize) -> Result<(), SeamProfileError> {
+        let profile = self.load_profile(id)?;
+        self.put_profile(id, &profile);
+        self.emit(SeamProfileEvent::ProfilesReloaded { ids: vec![id] });
+        Ok(())
+    }
+
+    /// 以复制方式替换当前生效配置的参数表，配置编号、名称及启用状态保持不变。
+    pub fn fill_current_profile(
+        &mut self,
+        v0: &SeamParamsV0,
+        ext: Option<&SeamParamsExtV1>,
+    ) -> Result<(), SeamProfileError> {
+        let id = self.current_index;
+        let current = self.current_profile();
+        let mut profile = SeamProfile::new(current.is_enabled(), id as i32);
+        profile.meta.set_name(current.name());
+        profile.v0 = *v0;
+        profile.v1_ext = ext.copied().unwrap_or(current.v1_ext);
+        profile.sync_meta();
+        self.check_profile(&profile)?;
+        self.put_profile(id, &profile);
+        self.emit(SeamProfileEvent::ProfilesReloaded { ids: vec![id] });
+        Ok(())
     }
 
     pub fn save_profile(&self, id: usize) -> Result<(), SeamProfileError> {
-        let path = format!("{}/seam-profile-{}.json", self.config_dir, id);
-        let text = serde_json::to_string(self.get_profile(id))?;
-        std::fs::write(&path, text)?;
+        let text = self.profile_text(id)?;
+        let path = self.profile_path(id);
+        self.mark_saved(id, &text);
+        write_atomic(&path, text)?;
         debug!("配置 #{} 已经保存到 {}", id, path);
         Ok(())
     }
 
-    pub fn save_all_profiles(&self) {
-        let n = self.profiles.len();
-        for i in 0..n {
-            if self.save_profile(i).is_ok() {
-                // TODO:
+    /// 返回指定编号配置保存到文件时的内容。
+    fn profile_text(&self, id: usize) -> Result<String, SeamProfileError> {
+        let profile = self.try_get_profile(id)?;
+        if !self.existing[id] {
+            return Err(SeamProfileError::NoSuchProfile(id as i32));
+        }
+        let value = profile.to_json_value(self.params_format)?;
+        Ok(match self.params_format {
+            SeamParamsFormat::Values => serde_json::to_string(&value)?,
+            SeamParamsFormat::Named => serde_json::to_string_pretty(&value)?,
+        })
+    }
+
+    /// 保存所有配置到文件，返回遇到的第一个错误。
+    pub fn save_all_profiles(&self) -> Result<(), SeamProfileError> {
+        let mut result = Ok(());
+        for i in (0..self.profiles.len()).filter(|&i| self.existing[i]) {
+            if let Err(err) = self.save_profile(i) {
+                error!("保存配置 #{} 失败：{}", i, err);
+                if result.is_ok() {
+                    result = Err(err);
+                }
             }
         }
+        result
     }
 
-    pub fn dump_profiles_string(&self, id: Option<i32>) -> String {
+    pub fn dump_profiles_string(&self, id: Option<i32>) -> Result<String, SeamProfileError> {
         let profiles = self
             .profiles
             .iter()
             .filter(|p| p.is_enabled() && id.map_or(true, |y| p.id() == y))
-            .collect::<Vec<&Box<SeamProfile>>>();
+            .map(|p| p.to_json_value(self.params_format))
+            .collect::<serde_json::Result<Vec<_>>>()?;
         let jx = serde_json::json!({
             "schema": SEAM_PROFILES_SCHEMA,
             "profiles": profiles,
         });
-        serde_json::to_string(&jx).unwrap_or_default()
+        Ok(serde_json::to_string(&jx)?)
     }
 
     pub fn dump_profiles_writer<W: Write>(&self, id: Option<i32>, w: W) -> serde_json::Result<()> {
@@ -1725,7 +1979,8 @@ impl SeamProfileManager {
             .profiles
             .iter()
             .filter(|p| p.is_enabled() && id.map_or(true, |y| p.id() == y))
-            .collect::<Vec<&Box<SeamProfile>>>();
+            .map(|p| p.to_json_value(self.params_format))
+            .collect::<serde_json::Result<Vec<_>>>()?;
         let jx = serde_json::json!({
             "schema": SEAM_PROFILES_SCHEMA,
             "profiles": profiles,
@@ -1733,24 +1988,37 @@ impl SeamProfileManager {
         serde_json::to_writer(w, &jx)
     }
 
-    pub fn to_json_string(&self) -> String {
+    pub fn to_json_string(&self) -> Result<String, SeamProfileError> {
+        let profiles = self
+            .profiles
+            .iter()
+            .map(|p| p.to_json_value(self.params_format))
+            .collect::<serde_json::Result<Vec<_>>>()?;
         let jx = serde_json::json!({
             "schema": SEAM_PROFILES_SCHEMA,
-            "profiles": &self.profiles[..],
+            "profiles": profiles,
         });
-        serde_json::to_string(&jx).unwrap_or_default()
+        Ok(serde_json::to_string(&jx)?)
     }
 
     pub fn to_json_writer<W: Write>(&self, w: W) -> serde_json::Result<()> {
+        let profiles = self
+            .profiles
+            .iter()
+            .map(|p| p.to_json_value(self.params_format))
+            .collect::<serde_json::Result<Vec<_>>>()?;
         let jx = serde_json::json!({
             "schema": SEAM_PROFILES_SCHEMA,
-            "profiles": &self.profiles[..],
+            "profiles": profiles,
         });
         serde_json::to_writer(w, &jx)
     }
 
     /// 转化为简化的接头识别配置档案信息列表。
-    pub fn dump_profiles_meta_only_string<W: Write>(&self, id: Option<i32>) -> String {
+    pub fn dump_profiles_meta_only_string(
+        &self,
+        id: Option<i32>,
+    ) -> Result<String, SeamProfileError> {
         let profiles = self
             .profiles
             .iter()
@@ -1765,7 +2033,7 @@ impl SeamProfileManager {
             schema: SEAM_PROFILES_META_ONLY_SCHEMA.into(),
             profiles,
         };
-        serde_json::to_string(&jx).unwrap_or_default()
+        Ok(serde_json::to_string(&jx)?)
     }
 
     /// 转化为简化的接头识别配置档案信息列表。
@@ -1809,8 +2077,10 @@ impl SeamProfileManager {
 
     pub fn set_cur_v0_value_f32(&mut self, index: SeamParamFlatId, value: f32) {
         if index.is_valid() {
+            let old = self.cur_v0().get(index);
             self.current_profile_mut().set_v0_value_f32(index, value);
             self.commit();
+            self.emit_param_changed(index, old);
         }
     }
 
@@ -1824,73 +2094,49 @@ impl SeamProfileManager {
 
     pub fn set_cur_v0_value_i32(&mut self, index: SeamParamFlatId, value: i32) {
         if index.is_valid() {
+            let old = self.cur_v0().get(index);
             self.current_profile_mut().set_v0_value_i32(index, value);
             self.commit();
+            self.emit_param_changed(index, old);
         }
     }
 
-    /// 自动配置所有配置。
-    pub fn auto_backup(&self) {
-        let prev_tarball = format!("{}/profiles-0.tar.gz", &self.backup_dir);
-        let curr_tarball = "/tmp/profiles-0.tar.gz";
-        if self.tar_all(curr_tarball).is_ok() {
-            let prev_digest = FileDigest::new(&prev_tarball);
-            if prev_digest.is_err() {
-                warn!("备份缺失，新建备份 {}", prev_tarball);
-                if let Err(err) = std::fs::copy(curr_tarball, &prev_tarball) {
-                    error!("写入备份 {} 失败：{}", prev_tarball, err);
-                }
-            } else {
-                let curr_digest = FileDigest::new(curr_tarball);
-                if let (Ok(prev), Ok(curr)) = (prev_digest, curr_digest) {
-                    if prev != curr {
-                        self.rotate_backup();
-                        info!("更新备份 {} -> {}", curr_tarball, prev_tarball);
-                        if let Err(err) = std::fs::copy(curr_tarball, &prev_tarball) {
-                            error!("写入备份 {} 失败：{}", prev_tarball, err);
-                        }
-                    } else {
-                        debug!("当前配置与备份一致");
-                    }
-                }
-            }
+    /// 按参数描述类型设置当前配置的参数值。
+    pub fn set_cur_v0<I, V>(&mut self, index: I, value: V)
+    where
+        I: Into<SeamParamFlatId>,
+        V: Into<SeamParamTypedValue>,
+    {
+        let index = index.into();
+        if index.is_valid() {
+            let old = self.cur_v0().get(index);
+            self.current_profile_mut().v0_mut().set(index, value);
+            self.current_profile_mut().sync_meta();
+            self.current_profile().commit();
+            self.commit();
+            self.emit_param_changed(index, old);
         }
     }
 
-    /// 循环备份。
-    fn rotate_backup(&self) {
-        for i in (0..3).rev() {
-            let src = format!("{}/profiles-{}.tar.gz", &self.backup_dir, i);
-            let dst = format!("{}/profiles-{}.tar.gz", &self.backup_dir, i + 1);
-            if Path::new(&src).exists() {
-                if let Err(err) = std::fs::copy(&src, &dst) {
-                    error!("循环备份 {} -> {} 失败：{}", src, dst, err);
-                } else {
-                    info!("循环备份 {} -> {}", src, dst);
-                }
+    fn emit_param_changed(&self, index: SeamParamFlatId, old: Option<SeamParamTypedValue>) {
+        if let (Some(old), Some(new)) = (old, self.cur_v0().get(index)) {
+            if old.to_raw() != new.to_raw() {
+                self.emit(SeamProfileEvent::ParamChanged {
+                    profile_id: self.current_index,
+                    id: index,
+                    old,
+                    new,
+                });
             }
         }
     }
 
-    /// 打包所有配置。
-    pub fn tar_all(&self, path: &str) -> io::Result<()> {
-        let status = Command::new("/usr/bin/tar")
-            .arg("zcf")
-            .arg(path)
-            .arg(".")
-            .current_dir(&self.config_dir)
-            .status()?;
-        if status.success() {
-            Ok(())
-        } else {
-            Err(io::Error::from_raw_os_error(status.code().unwrap_or(-1)))
-        }
-    }
-
+    /// 提交一次变更记录并发布当前生效的参数表。
     #[inline]
     pub fn commit(&self) {
         self.commits.fetch_add(1, Ordering::SeqCst);
         self.all_modified.set_now();
+        self.republish();
     }
 
     #[inline]
@@ -1914,17 +2160,39 @@ impl SeamProfileManager {
     }
 
     /// 当有变更时自动保存到文件中。
-    pub fn flush(&self) {
+    ///
+    /// 保存失败的配置保留变更记录，以便下次重试，返回遇到的第一个错误。
+    pub fn flush(&self) -> Result<(), SeamProfileError> {
+        let mut result = Ok(());
+        let mut ids = Vec::new();
         if self.commits() > 0 {
             self.clear_commits();
             self.flush_times.fetch_add(1, Ordering::SeqCst);
-            for p in &self.profiles {
-                if p.commits() > 0 {
-                    let _r = self.save_profile(p.id() as usize);
+            for (i, p) in self.profiles.iter().enumerate() {
+                if p.commits() > 0 && !self.existing[i] {
                     p.flush();
+                    self.discard_changes(i);
+                } else if p.commits() > 0 {
+                    match self.save_profile(i) {
+                        Ok(()) => {
+                            p.flush();
+                            ids.push(i);
+                        }
+                        Err(err) => {
+                            error!("保存配置 #{} 失败：{}", i, err);
+                            self.commits.fetch_add(1, Ordering::SeqCst);
+                            if result.is_ok() {
+                                result = Err(err);
+                            }
+                        }
+                    }
                 }
             }
         }
+        if !ids.is_empty() {
+            self.emit(SeamProfileEvent::Flushed { ids });
+        }
+        result
     }
 
     #[inline]
@@ -1938,6 +2206,18 @@ impl SeamProfileManager {
     }
 }
 
+/// 检查档案规范，为空时视为匹配。
+fn check_schema(found: &str, expected: &str) -> Result<(), SeamProfileError> {
+    if found.is_empty() || found == expected {
+        Ok(())
+    } else {
+        Err(SeamProfileError::SchemaMismatch {
+            expected: expected.into(),
+            found: found.into(),
+        })
+    }
+}
+
 impl Default for SeamProfileManager {
     fn default() -> Self {
         Self::new(DEFAULT_BACKUP_DIR, DEFAULT_CONFIG_DIR)
@@ -1982,72 +2262,452 @@ impl SeamProfilesMetaOnly<'_> {
     }
 }
 
-#[cfg(feature = "async")]
 macro_rules! get_spm {
-    () => {
-        SeamProfileManager::global().blocking_lock()
+    ($spm:expr) => {{
+        let mgr = SeamProfileManager::lock_blocking($spm.manager());
+        mgr.republish();
+        mgr
+    }};
+}
+
+pub type FvSeamProfileManager = SeamProfileManagerHandle;
+
+/// 将 C 字符串转换为 `&str`，空指针或非 UTF-8 字符串返回 `None`。
+unsafe fn ffi_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        CStr::from_ptr(s).to_str().ok()
+    }
+}
+
+/// 以指定目录初始化全局配置管理器，必须在首次使用全局配置管理器之前调用。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_init_global(
+    backup_dir: *const c_char,
+    config_dir: *const c_char,
+) -> i32 {
+    let (Some(backup_dir), Some(config_dir)) = (ffi_str(backup_dir), ffi_str(config_dir)) else {
+        return -1;
     };
+    match SeamProfileManager::init_global(backup_dir, config_dir, Default::default()) {
+        Ok(_) => 0,
+        Err(err) => {
+            error!("初始化全局配置管理器失败：{}", err);
+            -1
+        }
+    }
 }
 
-#[cfg(not(feature = "async"))]
-macro_rules! get_spm {
-    () => {
-        SeamProfileManager::global().lock().unwrap()
+/// 返回全局配置管理器的句柄，此句柄无需关闭。
+#[no_mangle]
+pub extern "C" fn fv_spm_global() -> *mut FvSeamProfileManager {
+    SeamProfileManagerHandle::global() as *const _ as *mut _
+}
+
+/// 以指定名称及目录创建并注册配置管理器实例，返回其句柄，失败时返回空指针。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_create(
+    name: *const c_char,
+    backup_dir: *const c_char,
+    config_dir: *const c_char,
+) -> *mut FvSeamProfileManager {
+    let (Some(name), Some(backup_dir), Some(config_dir)) =
+        (ffi_str(name), ffi_str(backup_dir), ffi_str(config_dir))
+    else {
+        return std::ptr::null_mut();
     };
+    let mgr = SeamProfileManager::try_new(backup_dir, config_dir)
+        .and_then(|mgr| SeamProfileManager::register_instance(name, mgr));
+    match mgr {
+        Ok(mgr) => Box::into_raw(Box::new(SeamProfileManagerHandle::new(mgr))),
+        Err(err) => {
+            error!("创建配置管理器实例 {} 失败：{}", name, err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// 返回指定名称的配置管理器实例的句柄，实例不存在时返回空指针。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_open(name: *const c_char) -> *mut FvSeamProfileManager {
+    match ffi_str(name).and_then(SeamProfileManager::instance) {
+        Some(mgr) => Box::into_raw(Box::new(SeamProfileManagerHandle::new(mgr))),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// 关闭由 [`fv_spm_create`] 或 [`fv_spm_open`] 返回的句柄。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_close(spm: *mut FvSeamProfileManager) {
+    if !spm.is_null() && !(*spm).is_global() {
+        drop(Box::from_raw(spm));
+    }
+}
+
+/// 注销指定名称的配置管理器实例，已打开的句柄仍然有效。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_remove(name: *const c_char) -> i32 {
+    match ffi_str(name).and_then(SeamProfileManager::remove_instance) {
+        Some(_) => 0,
+        None => -1,
+    }
 }
 
 /// 返回当前生效的接头识别参数配置。
+///
+/// 返回的指针指向管理器内部的配置，读写时不受管理器锁保护；
+/// 多线程环境下应使用 [`fv_spm_acquire_cur_profile`] 或 [`fv_spm_read_cur_v0`]；
+/// 通过指针修改参数后应调用 [`fv_spm_publish_cur`] 发布。
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn fv_spm_cur_profile() -> *mut FvSeamProfile {
-    let mut mgr = get_spm!();
+    fv_spmh_cur_profile(fv_spm_global())
+}
+
+/// 返回指定管理器当前生效的接头识别参数配置，@see [`fv_spm_cur_profile`]。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_cur_profile(spm: *mut FvSeamProfileManager) -> *mut FvSeamProfile {
+    let Some(spm) = spm.as_ref() else {
+        return std::ptr::null_mut();
+    };
+    let mut mgr = get_spm!(spm);
     mgr.current_profile_ffi_mut_ptr()
 }
 
+/// 一个代表由 [`fv_spm_acquire_cur_profile`] 分配的配置快照的类型。
+#[repr(C)]
+struct SeamProfileLease {
+    ffi: SeamProfileFFI,
+    params: SeamParamsV1,
+    meta: SeamProfileMeta,
+}
+
+/// 返回当前生效配置的快照，使用完毕后必须调用 [`fv_spm_release`] 释放。
+///
+/// 快照与管理器中的配置互不影响，可在任意线程中读写。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_acquire_cur_profile() -> *mut FvSeamProfile {
+    fv_spmh_acquire_cur_profile(fv_spm_global())
+}
+
+/// 返回指定管理器当前生效配置的快照，@see [`fv_spm_acquire_cur_profile`]。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_acquire_cur_profile(
+    spm: *mut FvSeamProfileManager,
+) -> *mut FvSeamProfile {
+    let Some(spm) = spm.as_ref() else {
+        return std::ptr::null_mut();
+    };
+    let mgr = get_spm!(spm);
+    let profile = mgr.current_profile();
+    let mut lease = Box::new(SeamProfileLease {
+        ffi: SeamProfileFFI {
+            enabled: profile.is_enabled() as i32,
+            id: profile.id(),
+            ..Default::default()
+        },
+        params: *profile.v1(),
+        meta: profile.meta().clone(),
+    });
+    drop(mgr);
+    lease.ffi.meta = &mut lease.meta as *mut SeamProfileMeta as *mut c_void;
+    lease.ffi.v0 = &mut lease.params.v0;
+    Box::into_raw(lease) as *mut FvSeamProfile
+}
+
+/// 释放由 [`fv_spm_acquire_cur_profile`] 返回的配置快照。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_release(spr: *mut FvSeamProfile) {
+    if !spr.is_null() {
+        drop(Box::from_raw(spr as *mut SeamProfileLease));
+    }
+}
+
+/// 以无锁方式复制当前生效的 V0 版参数表，返回配置编号，失败时返回 -1。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_read_cur_v0(dst: *mut FvSeamParamsV0) -> i32 {
+    fv_spmh_read_cur_v0(fv_spm_global(), dst)
+}
+
+/// 以无锁方式复制指定管理器当前生效的 V0 版参数表，@see [`fv_spm_read_cur_v0`]。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_read_cur_v0(
+    spm: *mut FvSeamProfileManager,
+    dst: *mut FvSeamParamsV0,
+) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    if dst.is_null() {
+        return -1;
+    }
+    let (id, v0) = spm.publisher().read_v0();
+    *dst = v0;
+    id as i32
+}
+
+/// 以无锁方式复制当前生效的 V1 版参数表，返回配置编号，失败时返回 -1。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_read_cur_v1(dst: *mut FvSeamParamsV1) -> i32 {
+    fv_spmh_read_cur_v1(fv_spm_global(), dst)
+}
+
+/// 以无锁方式复制指定管理器当前生效的 V1 版参数表，@see [`fv_spm_read_cur_v1`]。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_read_cur_v1(
+    spm: *mut FvSeamProfileManager,
+    dst: *mut FvSeamParamsV1,
+) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    if dst.is_null() {
+        return -1;
+    }
+    spm.publisher().read_into(&mut *dst) as i32
+}
+
+/// 返回当前生效参数表的发布代号，参数表变化或切换配置后代号增加。
+#[no_mangle]
+pub extern "C" fn fv_spm_cur_generation() -> u64 {
+    SeamProfileManager::global_publisher().generation()
+}
+
+/// 返回指定管理器当前生效参数表的发布代号，句柄为空时返回 0。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_cur_generation(spm: *mut FvSeamProfileManager) -> u64 {
+    spm.as_ref().map_or(0, |spm| spm.publisher().generation())
+}
+
+/// 通过指针直接修改当前配置后重新发布参数表，返回发布代号。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_publish_cur() -> u64 {
+    fv_spmh_publish_cur(fv_spm_global())
+}
+
+/// 通过指针直接修改指定管理器的当前配置后重新发布参数表，句柄为空时返回 0。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_publish_cur(spm: *mut FvSeamProfileManager) -> u64 {
+    let Some(spm) = spm.as_ref() else {
+        return 0;
+    };
+    drop(get_spm!(spm));
+    spm.publisher().generation()
+}
+
 /// 返回当前生效的接头识别参数配置编号。
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn fv_spm_cur_profile_id() -> i32 {
-    let mgr = get_spm!();
+    fv_spmh_cur_profile_id(fv_spm_global())
+}
+
+/// 返回指定管理器当前生效的接头识别参数配置编号，句柄为空时返回 -1。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_cur_profile_id(spm: *mut FvSeamProfileManager) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    let mgr = get_spm!(spm);
     mgr.current_profile_id() as i32
 }
 
-/// 加载指定编号的接头识别参数配置。
+/// 返回指定编号的接头识别参数配置，编号无效时返回空指针。
+///
+/// 返回的指针指向管理器内部的配置，在管理器的生命周期内保持有效，但读写时不受管理器锁保护；
+/// 通过指针修改当前生效的配置后应调用 [`fv_spm_publish_cur`] 发布。
 /// # Safety
 #[no_mangle]
-pub unsafe extern "C" fn fv_spm_load_profile(_id: i32) -> i32 {
-    // FIXME:
-    -1
+pub unsafe extern "C" fn fv_spm_profile(id: i32) -> *mut FvSeamProfile {
+    fv_spmh_profile(fv_spm_global(), id)
 }
 
-/// 加载指定编号的接头识别参数配置。
+/// 返回指定管理器中指定编号的接头识别参数配置，@see [`fv_spm_profile`]。
 /// # Safety
 #[no_mangle]
-pub unsafe extern "C" fn fv_spm_switch_profile(id: i32) -> i32 {
-    if !(0..=255).contains(&id) {
+pub unsafe extern "C" fn fv_spmh_profile(
+    spm: *mut FvSeamProfileManager,
+    id: i32,
+) -> *mut FvSeamProfile {
+    let Some(spm) = spm.as_ref() else {
+        return std::ptr::null_mut();
+    };
+    let mut mgr = get_spm!(spm);
+    match usize::try_from(id) {
+        Ok(id) if id < mgr.profiles_ffi.len() => &mut mgr.profiles_ffi[id] as *mut _,
+        _ => std::ptr::null_mut(),
+    }
+}
+
+/// 从文件中加载指定编号的接头识别参数配置。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_load_profile(id: i32) -> i32 {
+    fv_spmh_load_profile(fv_spm_global(), id)
+}
+
+/// 为指定管理器从文件中加载指定编号的接头识别参数配置。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_load_profile(spm: *mut FvSeamProfileManager, id: i32) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    if id < 0 {
         return -1;
     }
 
-    let mut mgr = get_spm!();
-    mgr.set_current_profile_id(id as usize);
+    let mut mgr = get_spm!(spm);
+    match mgr.reload_profile(id as usize) {
+        Ok(()) => 0,
+        Err(err) => {
+            error!("加载配置 #{} 失败：{}", id, err);
+            -1
+        }
+    }
+}
+
+/// 切换到指定编号的接头识别参数配置。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_switch_profile(id: i32) -> i32 {
+    fv_spmh_switch_profile(fv_spm_global(), id)
+}
+
+/// 将指定管理器切换到指定编号的接头识别参数配置。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_switch_profile(spm: *mut FvSeamProfileManager, id: i32) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    if id < 0 {
+        return -1;
+    }
 
-    0
+    let mut mgr = get_spm!(spm);
+    match mgr.switch_profile(id as usize) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
 }
 
 /// 设置当前生效的接头识别参数配置（以复制方式）。
 /// # Safety
 #[no_mangle]
-pub unsafe extern "C" fn fv_spm_fill_cur_profile(_sp: *const FvSeamProfile) -> i32 {
-    // FIXME:
-    -1
+pub unsafe extern "C" fn fv_spm_fill_cur_profile(sp: *const FvSeamProfile) -> i32 {
+    fv_spmh_fill_cur_profile(fv_spm_global(), sp)
+}
+
+/// 设置指定管理器当前生效的接头识别参数配置（以复制方式）。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_fill_cur_profile(
+    spm: *mut FvSeamProfileManager,
+    sp: *const FvSeamProfile,
+) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    if sp.is_null() || (*sp).v0.is_null() {
+        return -1;
+    }
+
+    let v0 = *(*sp).v0;
+    let mut mgr = get_spm!(spm);
+    match mgr.fill_current_profile(&v0, None) {
+        Ok(()) => 0,
+        Err(err) => {
+            error!("设置当前配置失败：{}", err);
+            -1
+        }
+    }
+}
+
+/// 以复制方式设置当前生效的 V1 版参数表，配置编号、名称及启用状态保持不变。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spm_fill_cur_profile_v1(spa: *const FvSeamParamsV1) -> i32 {
+    fv_spmh_fill_cur_profile_v1(fv_spm_global(), spa)
+}
+
+/// 以复制方式设置指定管理器当前生效的 V1 版参数表，@see [`fv_spm_fill_cur_profile_v1`]。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_fill_cur_profile_v1(
+    spm: *mut FvSeamProfileManager,
+    spa: *const FvSeamParamsV1,
+) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    let Some(v1) = spa.as_ref().copied() else {
+        return -1;
+    };
+    let mut mgr = get_spm!(spm);
+    match mgr.fill_current_profile(&v1.v0, Some(&v1.ext)) {
+        Ok(()) => 0,
+        Err(err) => {
+            error!("设置当前配置失败：{}", err);
+            -1
+        }
+    }
 }
 
 /// 设置当前生效的接头识别参数配置（以非复制方式）。
+///
+/// 配置必须由管理器分配，如 [`fv_spm_profile`] 的返回值，否则返回 -1；
+/// 效果等同于以其编号切换配置，调用方自行分配的配置请使用 [`fv_spm_fill_cur_profile`]。
 /// # Safety
 #[no_mangle]
-pub unsafe extern "C" fn fv_spm_set_cur_profile_ptr(_sp: *mut FvSeamProfile) -> i32 {
-    // FIXME:
-    -1
+pub unsafe extern "C" fn fv_spm_set_cur_profile_ptr(sp: *mut FvSeamProfile) -> i32 {
+    fv_spmh_set_cur_profile_ptr(fv_spm_global(), sp)
+}
+
+/// 设置指定管理器当前生效的接头识别参数配置（以非复制方式）。
+///
+/// 配置必须由该管理器分配，如 [`fv_spmh_profile`] 的返回值，否则返回 -1。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spmh_set_cur_profile_ptr(
+    spm: *mut FvSeamProfileManager,
+    sp: *mut FvSeamProfile,
+) -> i32 {
+    let Some(spm) = spm.as_ref() else {
+        return -1;
+    };
+    if sp.is_null() {
+        return -1;
+    }
+
+    let v0 = (*sp).v0;
+    let mut mgr = get_spm!(spm);
+    let Some(id) = mgr.profiles_ffi.iter().position(|p| p.v0 == v0) else {
+        error!("配置 {:p} 不是由管理器分配的", sp);
+        return -1;
+    };
+    match mgr.switch_profile(id) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
 }
 
 /// 返回 FvSeamProfile 中的 FvSeamParamsV0 参数。
@@ -2060,6 +2720,8 @@ pub unsafe extern "C" fn fv_spr_v0(spr: *mut FvSeamProfile) -> *mut FvSeamParams
 }
 
 /// 返回当前生效的 FvSeamParamsV0 参数。
+///
+/// 与 [`fv_spm_cur_profile`] 相同，读写时不受管理器锁保护。
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn fv_spa_v0_cur() -> *mut FvSeamParamsV0 {
@@ -2068,6 +2730,24 @@ pub unsafe extern "C" fn fv_spa_v0_cur() -> *mut FvSeamParamsV0 {
     spr.v0
 }
 
+/// 返回 FvSeamProfile 中的 FvSeamParamsV1 参数。
+///
+/// 配置必须由管理器分配，其 V0 版参数表之后紧接扩展参数表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spr_v1(spr: *mut FvSeamProfile) -> *mut FvSeamParamsV1 {
+    assert!(!spr.is_null());
+    let spr = &mut *spr;
+    spr.v0 as *mut FvSeamParamsV1
+}
+
+/// 返回当前生效的 FvSeamParamsV1 参数。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v1_cur() -> *mut FvSeamParamsV1 {
+    fv_spr_v1(fv_spm_cur_profile())
+}
+
 /// 返回 FvSeamParamsV0 平面空间中指定寄存器的 32 位浮点参数值。
 /// # Safety
 #[no_mangle]
@@ -2092,6 +2772,14 @@ pub unsafe extern "C" fn fv_spa_v0_xp_f32(spa: *mut FvSeamParamsV0, index: FvSea
     (*spa).xp_f32(index)
 }
 
+/// 返回 FvSeamParamsV0 中的 XP 值表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_xpv(spa: *mut FvSeamParamsV0) -> *mut FvSeamParamValue {
+    assert!(!spa.is_null());
+    (*spa).parts.xp.as_mut_ptr()
+}
+
 /// 返回 FvSeamParamsV0 中指定寄存器的 32 位整型参数 XP 值。
 /// # Safety
 #[no_mangle]
@@ -2108,6 +2796,14 @@ pub unsafe extern "C" fn fv_spa_v0_kp_f32(spa: *mut FvSeamParamsV0, index: FvSea
     (*spa).kp_f32(index)
 }
 
+/// 返回 FvSeamParamsV0 中的 KP 值表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_kpv(spa: *mut FvSeamParamsV0) -> *mut FvSeamParamValue {
+    assert!(!spa.is_null());
+    (*spa).parts.kp.as_mut_ptr()
+}
+
 /// 返回 FvSeamParamsV0 中指定寄存器的 32 位整型参数 KP 值。
 /// # Safety
 #[no_mangle]
@@ -2124,6 +2820,14 @@ pub unsafe extern "C" fn fv_spa_v0_op_f32(spa: *mut FvSeamParamsV0, index: FvSea
     (*spa).op_f32(index)
 }
 
+/// 返回 FvSeamParamsV0 中的 OP 值表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_opv(spa: *mut FvSeamParamsV0) -> *mut FvSeamParamValue {
+    assert!(!spa.is_null());
+    (*spa).parts.op.as_mut_ptr()
+}
+
 /// 返回 FvSeamParamsV0 中指定寄存器的 32 位整型参数 OP 值。
 /// # Safety
 #[no_mangle]
@@ -2140,6 +2844,14 @@ pub unsafe extern "C" fn fv_spa_v0_vp_f32(spa: *mut FvSeamParamsV0, index: FvSea
     (*spa).vp_f32(index)
 }
 
+/// 返回 FvSeamParamsV0 中的 VP 值表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_vpv(spa: *mut FvSeamParamsV0) -> *mut FvSeamParamValue {
+    assert!(!spa.is_null());
+    (*spa).parts.vp.as_mut_ptr()
+}
+
 /// 返回 FvSeamParamsV0 中指定寄存器的 32 位整型参数 VP 值。
 /// # Safety
 #[no_mangle]
@@ -2156,6 +2868,14 @@ pub unsafe extern "C" fn fv_spa_v0_oc_f32(spa: *mut FvSeamParamsV0, index: FvSea
     (*spa).oc_f32(index)
 }
 
+/// 返回 FvSeamParamsV0 中的 OC 值表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_ocv(spa: *mut FvSeamParamsV0) -> *mut FvSeamParamValue {
+    assert!(!spa.is_null());
+    (*spa).parts.oc.as_mut_ptr()
+}
+
 /// 返回 FvSeamParamsV0 中指定寄存器的 32 位整型参数 OC 值。
 /// # Safety
 #[no_mangle]
@@ -2172,6 +2892,14 @@ pub unsafe extern "C" fn fv_spa_v0_sf_f32(spa: *mut FvSeamParamsV0, index: FvSea
     (*spa).sf_f32(index)
 }
 
+/// 返回 FvSeamParamsV0 中的 SF 值表。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_sfv(spa: *mut FvSeamParamsV0) -> *mut FvSeamParamValue {
+    assert!(!spa.is_null());
+    (*spa).parts.sf.as_mut_ptr()
+}
+
 /// 返回 FvSeamParamsV0 中指定寄存器的 32 位整型参数 SF 值。
 /// # Safety
 #[no_mangle]
@@ -2220,6 +2948,66 @@ pub unsafe extern "C" fn fv_spa_v0_oc_en(spa: *mut FvSeamParamsV0, index: FvSeam
     (*spa).oc_en(index)
 }
 
+/// 设置 FvSeamParamsV0 中的指定索引的 XP 参数开关。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_set_xp_en(
+    spa: *mut FvSeamParamsV0,
+    index: FvSeamParamXpId,
+    en: i32,
+) {
+    assert!(!spa.is_null());
+    (*spa).set_xp_en(index, en != 0);
+}
+
+/// 设置 FvSeamParamsV0 中的指定索引的 KP 参数开关。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_set_kp_en(
+    spa: *mut FvSeamParamsV0,
+    index: FvSeamParamKpId,
+    en: i32,
+) {
+    assert!(!spa.is_null());
+    (*spa).set_kp_en(index, en != 0);
+}
+
+/// 设置 FvSeamParamsV0 中的指定索引的 OP 参数开关。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_set_op_en(
+    spa: *mut FvSeamParamsV0,
+    index: FvSeamParamOpId,
+    en: i32,
+) {
+    assert!(!spa.is_null());
+    (*spa).set_op_en(index, en != 0);
+}
+
+/// 设置 FvSeamParamsV0 中的指定索引的 VP 参数开关。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_set_vp_en(
+    spa: *mut FvSeamParamsV0,
+    index: FvSeamParamVpId,
+    en: i32,
+) {
+    assert!(!spa.is_null());
+    (*spa).set_vp_en(index, en != 0);
+}
+
+/// 设置 FvSeamParamsV0 中的指定索引的 OC 参数开关。
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn fv_spa_v0_set_oc_en(
+    spa: *mut FvSeamParamsV0,
+    index: FvSeamParamOcId,
+    en: i32,
+) {
+    assert!(!spa.is_null());
+    (*spa).set_oc_en(index, en != 0);
+}
+
 /// 返回 FvSeamParamsV0 中的主要接头形式值。
 /// # Safety
 #[no_mangle]
@@ -2247,12 +3035,19 @@ pub unsafe extern "C" fn fv_spa_v0_version(spa: *mut FvSeamParamsV0) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::TestDir;
 
     #[test]
     fn test_seam_profile_manager() {
-        let mut mgr = SeamProfileManager::new(DEFAULT_BACKUP_DIR, DEFAULT_CONFIG_DIR);
-        mgr.load_all_profiles();
-        mgr.save_all_profiles();
+        let dir = TestDir::new("seam-profile-manager");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        mgr.load_all_profiles().unwrap();
+        mgr.save_all_profiles().unwrap();
+        assert!(matches!(
+            mgr.save_profile(256),
+            Err(SeamProfileError::InvalidId(256))
+        ));
     }
 
     #[test]
@@ -2263,6 +3058,10 @@ mod tests {
         }
         "#;
         let _v0: SeamParamsV0 = serde_json::from_str(s).unwrap();
+        assert!(serde_json::from_str::<SeamParamsV0>(r#"{"values":[1,2,"x"]}"#).is_err());
+        let values = serde_json::to_string(&vec![0; 251]).unwrap();
+        let s = format!(r#"{{"values":{}}}"#, values);
+        assert!(serde_json::from_str::<SeamParamsV0>(&s).is_err());
     }
 
     #[test]
@@ -0,0 +1,252 @@
+use super::{
+    write_atomic, SeamProfile, SeamProfileDiff, SeamProfileError, SeamProfileEvent,
+    SeamProfileManager,
+};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::{debug, info};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// 暂存文件序号，用于区分同一进程中的多个配置管理器。
+static STAGING_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// 返回新的备份暂存文件路径。
+///
+/// 暂存文件位于备份目录中，以 `.` 开头，不会被当作备份文件；
+/// 文件名包含进程号及序号，多个进程或管理器同时备份时互不影响。
+pub(super) fn staging_path(backup_dir: &str) -> PathBuf {
+    let seq = STAGING_SEQ.fetch_add(1, Ordering::Relaxed);
+    PathBuf::from(format!(
+        "{}/.profiles-staging-{}-{}.tar.gz",
+        backup_dir,
+        std::process::id(),
+        seq
+    ))
+}
+
+/// 从文件名中解析配置编号，兼容 `./seam-profile-N.json` 形式。
+pub(super) fn profile_id_from_name(path: &Path) -> Option<usize> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix("seam-profile-")?
+        .strip_suffix(".json")?
+        .parse()
+        .ok()
+}
+
+/// 将配置文件打包到指定路径，@see [`SeamProfileManager::tar_all`]。
+pub(super) fn write_tarball(
+    path: &Path,
+    files: &[(usize, Vec<u8>)],
+) -> Result<(), SeamProfileError> {
+    let backup_err = |err: std::io::Error| {
+        SeamProfileError::Backup(format!("打包 {} 失败：{}", path.display(), err))
+    };
+    let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    for (id, data) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        header.set_entry_type(tar::EntryType::Regular);
+        builder
+            .append_data(
+                &mut header,
+                format!("./seam-profile-{}.json", id),
+                &data[..],
+            )
+            .map_err(backup_err)?;
+    }
+    let data = builder
+        .into_inner()
+        .and_then(|gz| gz.finish())
+        .map_err(backup_err)?;
+    write_atomic(path, data)?;
+    debug!("{} 个配置已经打包到 {}", files.len(), path.display());
+    Ok(())
+}
+
+/// 遍历备份文件中的所有配置文件，回调参数为配置编号及文件内容。
+fn for_each_entry<F>(tarball: &Path, mut f: F) -> Result<(), SeamProfileError>
+where
+    F: FnMut(usize, Vec<u8>) -> Result<bool, SeamProfileError>,
+{
+    let backup_err = |err: std::io::Error| {
+        SeamProfileError::Backup(format!("解包 {} 失败：{}", tarball.display(), err))
+    };
+    let file = File::open(tarball)?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    for entry in archive.entries().map_err(backup_err)? {
+        let mut entry = entry.map_err(backup_err)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let Some(id) = profile_id_from_name(&entry.path().map_err(backup_err)?) else {
+            continue;
+        };
+        let mut data = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut data).map_err(backup_err)?;
+        if !f(id, data)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+impl SeamProfileManager {
+    /// 打包所有配置文件到指定路径。
+    ///
+    /// 生成标准 tar.gz 文件，条目名称为 `./seam-profile-N.json`，
+    /// 不记录修改时间，内容相同的配置生成的备份文件完全一致。
+    pub fn tar_all<P: AsRef<Path>>(&self, path: P) -> Result<(), SeamProfileError> {
+        write_tarball(path.as_ref(), &self.read_profile_files()?)
+    }
+
+    /// 按编号顺序读取配置目录中的所有配置文件。
+    pub(super) fn read_profile_files(&self) -> Result<Vec<(usize, Vec<u8>)>, SeamProfileError> {
+        let mut files: Vec<(usize, PathBuf)> = std::fs::read_dir(&self.config_dir)?
+            .flatten()
+            .map(|e| e.path())
+            .filter_map(|p| Some((profile_id_from_name(&p)?, p)))
+            .filter(|(_, p)| p.is_file())
+            .collect();
+        files.sort_unstable();
+        files
+            .into_iter()
+            .map(|(id, file)| Ok((id, std::fs::read(file)?)))
+            .collect()
+    }
+
+    /// 读取备份文件中的所有配置，每个配置均经过档案规范、编号及参数校验。
+    pub fn read_archive<P: AsRef<Path>>(
+        &self,
+        tarball: P,
+    ) -> Result<Vec<SeamProfile>, SeamProfileError> {
+        let mut profiles = Vec::new();
+        for_each_entry(tarball.as_ref(), |id, data| {
+            profiles.push(self.parse_archive_entry(id, &data)?);
+            Ok(true)
+        })?;
+        profiles.sort_by_key(|p| p.id());
+        Ok(profiles)
+    }
+
+    /// 读取备份文件中指定编号的配置。
+    pub fn read_archive_profile<P: AsRef<Path>>(
+        &self,
+        tarball: P,
+        id: usize,
+    ) -> Result<SeamProfile, SeamProfileError> {
+        let tarball = tarball.as_ref();
+        let mut found = None;
+        for_each_entry(tarball, |i, data| {
+            if i == id {
+                found = Some(self.parse_archive_entry(id, &data)?);
+            }
+            Ok(found.is_none())
+        })?;
+        found.ok_or_else(|| {
+            SeamProfileError::Backup(format!("{} 中不存在配置 #{}", tarball.display(), id))
+        })
+    }
+
+    /// 从备份文件中恢复所有配置，返回与当前配置的差异。
+    ///
+    /// 所有配置均通过校验后才会合并到管理器中并立即保存到文件；
+    /// `dry_run` 为 `true` 时仅返回差异，不修改当前配置。
+    pub fn restore_from_archive<P: AsRef<Path>>(
+        &mut self,
+        tarball: P,
+        dry_run: bool,
+    ) -> Result<Vec<SeamProfileDiff>, SeamProfileError> {
+        let tarball = tarball.as_ref();
+        let profiles = self.read_archive(tarball)?;
+        let diffs: Vec<SeamProfileDiff> = profiles
+            .iter()
+            .map(|p| self.get_profile(p.id() as usize).diff(p))
+            .filter(|d| !d.is_empty())
+            .collect();
+        if dry_run {
+            return Ok(diffs);
+        }
+        for diff in &diffs {
+            let id = diff.profile_id;
+            let profile = &profiles[profiles.partition_point(|p| (p.id() as usize) < id)];
+            self.put_profile(id, profile);
+        }
+        if !diffs.is_empty() {
+            self.emit(SeamProfileEvent::ProfilesReloaded {
+                ids: diffs.iter().map(|d| d.profile_id).collect(),
+            });
+            self.flush()?;
+        }
+        info!(
+            "{} 个配置已经从 {} 恢复，其中 {} 个有变更",
+            profiles.len(),
+            tarball.display(),
+            diffs.len()
+        );
+        Ok(diffs)
+    }
+
+    /// 解析并校验备份文件中的配置。
+    fn parse_archive_entry(&self, id: usize, data: &[u8]) -> Result<SeamProfile, SeamProfileError> {
+        self.try_get_profile(id)?;
+        let mut profile = serde_json::from_slice::<SeamProfile>(data)?;
+        profile.migrate()?;
+        if profile.id() != id as i32 {
+            return Err(SeamProfileError::InvalidId(profile.id()));
+        }
+        self.check_profile(&profile)?;
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_tar_and_restore() {
+        let dir = TestDir::new("archive");
+        let dir = dir.to_str();
+        let mut mgr =
+            SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir))
+                .unwrap();
+        mgr.save_all_profiles().unwrap();
+        mgr.get_profile_mut(9).set_name("Archived");
+        mgr.save_profile(9).unwrap();
+
+        let tarball = format!("{}/backup/profiles-0.tar.gz", dir);
+        mgr.tar_all(&tarball).unwrap();
+        let again = format!("{}/backup/profiles-1.tar.gz", dir);
+        mgr.tar_all(&again).unwrap();
+        assert_eq!(
+            std::fs::read(&tarball).unwrap(),
+            std::fs::read(&again).unwrap()
+        );
+
+        let profiles = mgr.read_archive(&tarball).unwrap();
+        assert_eq!(profiles.len(), 256);
+        assert_eq!(
+            mgr.read_archive_profile(&tarball, 9).unwrap().name(),
+            "Archived"
+        );
+
+        mgr.get_profile_mut(9).set_name("Changed");
+        let diffs = mgr.restore_from_archive(&tarball, true).unwrap();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].name, Some(("Changed".into(), "Archived".into())));
+        assert_eq!(mgr.get_profile(9).name(), "Changed");
+
+        mgr.restore_from_archive(&tarball, false).unwrap();
+        assert_eq!(mgr.get_profile(9).name(), "Archived");
+        assert_eq!(mgr.commits(), 0);
+        assert_eq!(mgr.load_profile(9).unwrap().name(), "Archived");
+    }
+}
@@ -0,0 +1,193 @@
+use super::{
+    SeamBackupEntry, SeamProfileError, SeamProfileLoadReport, SeamProfileManager,
+    SeamProfileManagerLock,
+};
+use log::{debug, error};
+use std::ffi::OsString;
+use std::io;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+
+impl SeamProfileManager {
+    /// 以异步方式从文件加载所有配置，@see [`SeamProfileManager::load_all_profiles`]。
+    ///
+    /// 仅在解析配置时持有管理器锁，读取文件及重建缺失或损坏的配置文件期间不持有管理器锁。
+    pub async fn load_all_profiles_async(
+        mgr: &SeamProfileManagerLock,
+    ) -> Result<SeamProfileLoadReport, SeamProfileError> {
+        let paths: Vec<String> = {
+            let mgr = Self::lock_async(mgr).await;
+            (0..mgr.slots()).map(|i| mgr.profile_path(i)).collect()
+        };
+        let mut texts = Vec::with_capacity(paths.len());
+        for path in &paths {
+            texts.push(tokio::fs::read_to_string(path).await);
+        }
+
+        let (report, rebuilds) = Self::lock_async(mgr)
+            .await
+            .load_all_profiles_with(|mgr, i| {
+                let text = std::mem::replace(&mut texts[i], Err(io::ErrorKind::NotFound.into()))?;
+                let profile = mgr.parse_profile(&text)?;
+                debug!("配置 #{} 已经从 {} 加载", i, paths[i]);
+                Ok(profile)
+            })?;
+        for rebuild in rebuilds {
+            if let Some(corrupt) = &rebuild.corrupt {
+                tokio::fs::rename(&rebuild.path, corrupt).await?;
+            }
+            if Self::write_profile_async(mgr, rebuild.id, &rebuild.path, &rebuild.text).await? {
+                debug!("配置 #{} 已经保存到 {}", rebuild.id, rebuild.path);
+            }
+        }
+        Ok(report)
+    }
+
+    /// 以异步方式保存有变更的配置，@see [`SeamProfileManager::flush`]。
+    ///
+    /// 仅在生成文件内容及替换文件时持有管理器锁，写入临时文件期间其他线程可正常访问管理器。
+    /// 多个保存操作依次执行；替换文件前配置已被再次保存时放弃本次写入，不会以旧内容覆盖新内容。
+    pub async fn flush_async(mgr: &SeamProfileManagerLock) -> Result<(), SeamProfileError> {
+        let flush_lock = Self::lock_async(mgr).await.flush_lock.clone();
+        let _flushing = flush_lock.lock().await;
+
+        let prepared = Self::lock_async(mgr).await.prepare_flush();
+        let Some((jobs, mut result)) = prepared else {
+            return Ok(());
+        };
+        let mut ids = Vec::new();
+        let mut failed = Vec::new();
+        for job in jobs {
+            match Self::write_profile_async(mgr, job.id, &job.path, &job.text).await {
+                Ok(true) => {
+                    debug!("配置 #{} 已经保存到 {}", job.id, job.path);
+                    ids.push(job.id);
+                }
+                Ok(false) => debug!("配置 #{} 已有更新的内容保存，放弃写入", job.id),
+                Err(err) => {
+                    error!("保存配置 #{} 失败：{}", job.id, err);
+                    failed.push(job.id);
+                    if result.is_ok() {
+                        result = Err(err.into());
+                    }
+                }
+            }
+        }
+        Self::lock_async(mgr).await.finish_flush(ids, failed);
+        result
+    }
+
+    /// 以原子方式写入配置文件，返回是否写入。
+    ///
+    /// 内容先写入临时文件，再持有管理器锁确认内容仍为最近一次保存的内容后替换目标文件，
+    /// 否则删除临时文件并返回 `false`。
+    async fn write_profile_async(
+        mgr: &SeamProfileManagerLock,
+        id: usize,
+        path: &str,
+        text: &str,
+    ) -> io::Result<bool> {
+        let path = Path::new(path);
+        let mut tmp_name = OsString::from(".");
+        tmp_name.push(path.file_name().unwrap_or_default());
+        tmp_name.push(".async.tmp");
+        let tmp_path = path.with_file_name(tmp_name);
+
+        let r = async {
+            let mut file = tokio::fs::File::create(&tmp_path).await?;
+            file.write_all(text.as_bytes()).await?;
+            file.sync_all().await?;
+            let mgr = Self::lock_async(mgr).await;
+            if !mgr.is_saved(id, text) {
+                return Ok(false);
+            }
+            std::fs::rename(&tmp_path, path)?;
+            Ok(true)
+        }
+        .await;
+        if !matches!(r, Ok(true)) {
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return r;
+        }
+
+        // 同步目录项，确保重命名操作落盘。
+        #[cfg(unix)]
+        if let Some(dir) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::File::open(dir).await?.sync_all().await?;
+        }
+        Ok(true)
+    }
+
+    /// 以异步方式自动备份所有配置，@see [`SeamProfileManager::auto_backup`]。
+    ///
+    /// 仅在读取配置文件时持有管理器锁，打包、压缩及写入备份文件在阻塞线程池中执行，
+    /// 期间不持有管理器锁，也不会阻塞异步运行时的工作线程。
+    pub async fn auto_backup_async(
+        mgr: &SeamProfileManagerLock,
+    ) -> Result<Option<SeamBackupEntry>, SeamProfileError> {
+        let snapshot = Self::lock_async(mgr).await.backup_snapshot()?;
+        tokio::task::spawn_blocking(move || snapshot.write())
+            .await
+            .map_err(|err| SeamProfileError::Backup(err.to_string()))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_async_io() {
+        let dir = TestDir::new("async-io");
+        let dir = dir.to_str();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let mgr = Arc::new(SeamProfileManagerLock::new(SeamProfileManager::new(
+                format!("{}/backup", dir),
+                format!("{}/config", dir),
+            )));
+            let report = SeamProfileManager::load_all_profiles_async(&mgr)
+                .await
+                .unwrap();
+            assert_eq!(report.created.len(), 256);
+
+            let path = {
+                let mut mgr = SeamProfileManager::lock_async(&mgr).await;
+                mgr.enable_profile(4);
+                mgr.rename_profile(4, "Async").unwrap();
+                mgr.profile_path(4)
+            };
+            SeamProfileManager::flush_async(&mgr).await.unwrap();
+            assert!(std::fs::read_to_string(&path).unwrap().contains("Async"));
+            assert_eq!(SeamProfileManager::lock_async(&mgr).await.commits(), 0);
+
+            let text = std::fs::read_to_string(&path).unwrap();
+            SeamProfileManager::lock_async(&mgr)
+                .await
+                .mark_saved(4, "newer");
+            let written = SeamProfileManager::write_profile_async(&mgr, 4, &path, "stale")
+                .await
+                .unwrap();
+            assert!(!written);
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+
+            let report = SeamProfileManager::load_all_profiles_async(&mgr)
+                .await
+                .unwrap();
+            assert_eq!(report.loaded.len(), 256);
+            std::fs::write(&path, "{").unwrap();
+            let report = SeamProfileManager::load_all_profiles_async(&mgr)
+                .await
+                .unwrap();
+            assert_eq!(report.reset, vec![4]);
+            assert!(std::path::Path::new(&format!("{}.corrupt", path)).exists());
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+            let entry = SeamProfileManager::auto_backup_async(&mgr).await.unwrap();
+            assert!(entry.is_some());
+        });
+    }
+}
@@ -0,0 +1,342 @@
+use super::archive::write_tarball;
+use super::recovery::backup_tarballs;
+use super::{
+    write_atomic, FileDigest, SeamProfile, SeamProfileDiff, SeamProfileError, SeamProfileManager,
+};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 备份清单文件名。
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// 一个代表备份保留策略的类型。
+///
+/// 最新的备份始终保留，其余备份超出数量或时长任一限制时删除。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamBackupRetention {
+    /// 最多保留的备份数量，为 `None` 时不限制。
+    pub max_count: Option<usize>,
+    /// 最长保留时长，为 `None` 时不限制。
+    pub max_age: Option<Duration>,
+}
+
+impl Default for SeamBackupRetention {
+    fn default() -> Self {
+        Self {
+            max_count: Some(4),
+            max_age: None,
+        }
+    }
+}
+
+/// 一个代表备份记录的类型。
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeamBackupEntry {
+    /// 备份代号，按创建顺序递增。
+    pub generation: u64,
+    /// 备份文件名，位于备份目录中。
+    pub file: String,
+    /// 创建时间，自 UNIX 纪元起的秒数。
+    pub created: u64,
+    /// 备份文件的 SHA-256 摘要。
+    pub digest: String,
+    /// 备份文件大小。
+    pub size: u64,
+}
+
+impl SeamBackupEntry {
+    /// 返回创建时间。
+    pub fn created_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.created)
+    }
+}
+
+/// 备份清单，按代号从新到旧排列。
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SeamBackupManifest {
+    next_generation: u64,
+    backups: Vec<SeamBackupEntry>,
+}
+
+/// 返回自 UNIX 纪元起的秒数。
+fn unix_secs(t: SystemTime) -> u64 {
+    t.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl SeamProfileManager {
+    /// 自动备份所有配置，返回新建的备份记录。
+    ///
+    /// 备份文件以创建时间及摘要命名，内容与最新备份一致时不重复备份，
+    /// 新建备份后按 [`SeamBackupRetention`] 清理旧备份。
+    pub fn auto_backup(&self) -> Result<Option<SeamBackupEntry>, SeamProfileError> {
+        self.backup_snapshot()?.write()
+    }
+
+    /// 读取所有配置文件作为备份快照，打包及写入备份文件时无需持有管理器锁。
+    pub(super) fn backup_snapshot(&self) -> Result<SeamBackupSnapshot, SeamProfileError> {
+        Ok(SeamBackupSnapshot {
+            store: self.backup_store(),
+            files: self.read_profile_files()?,
+        })
+    }
+
+    /// 返回备份目录。
+    fn backup_store(&self) -> SeamBackupStore {
+        SeamBackupStore {
+            backup_dir: self.backup_dir.clone(),
+            retention: self.backup_retention,
+            staging: self.staging_path.clone(),
+            lock: self.backup_lock.clone(),
+        }
+    }
+
+    /// 返回所有备份记录，按代号从新到旧排列。
+    pub fn list_backups(&self) -> Result<Vec<SeamBackupEntry>, SeamProfileError> {
+        Ok(self.backup_store().load_manifest()?.backups)
+    }
+
+    /// 返回指定代号的备份记录。
+    pub fn backup_entry(&self, generation: u64) -> Result<SeamBackupEntry, SeamProfileError> {
+        self.list_backups()?
+            .into_iter()
+            .find(|e| e.generation == generation)
+            .ok_or(SeamProfileError::NoSuchBackup(generation))
+    }
+
+    /// 读取指定代号备份中的所有配置，读取前校验文件摘要。
+    pub fn inspect_backup(&self, generation: u64) -> Result<Vec<SeamProfile>, SeamProfileError> {
+        let path = self.verify_backup(generation)?;
+        self.read_archive(path)
+    }
+
+    /// 从指定代号的备份中恢复所有配置，返回与当前配置的差异。
+    ///
+    /// `dry_run` 为 `true` 时仅返回差异，不修改当前配置。
+    pub fn restore_backup(
+        &mut self,
+        generation: u64,
+        dry_run: bool,
+    ) -> Result<Vec<SeamProfileDiff>, SeamProfileError> {
+        let path = self.verify_backup(generation)?;
+        self.restore_from_archive(path, dry_run)
+    }
+
+    /// 校验指定代号备份的文件摘要，返回备份文件路径。
+    fn verify_backup(&self, generation: u64) -> Result<PathBuf, SeamProfileError> {
+        let entry = self.backup_entry(generation)?;
+        let path = self.backup_store().backup_path(&entry.file);
+        let digest = FileDigest::new(&path)?.to_string();
+        if digest != entry.digest {
+            return Err(SeamProfileError::Backup(format!(
+                "备份 {} 摘要不匹配，期望 {}，实际 {}",
+                path.display(),
+                entry.digest,
+                digest
+            )));
+        }
+        Ok(path)
+    }
+}
+
+/// 一个代表待备份的配置文件快照的类型。
+pub(super) struct SeamBackupSnapshot {
+    store: SeamBackupStore,
+    files: Vec<(usize, Vec<u8>)>,
+}
+
+impl SeamBackupSnapshot {
+    /// 打包快照并登记为新备份，@see [`SeamProfileManager::auto_backup`]。
+    ///
+    /// 同一管理器的多个备份操作依次执行。
+    pub(super) fn write(self) -> Result<Option<SeamBackupEntry>, SeamProfileError> {
+        let _backing_up = self
+            .store
+            .lock
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        let staging = &self.store.staging;
+        write_tarball(staging, &self.files)?;
+        let result = self.store.commit_backup(staging);
+        let _ = std::fs::remove_file(staging);
+        result
+    }
+}
+
+/// 一个代表备份目录的类型，不引用配置管理器。
+struct SeamBackupStore {
+    backup_dir: String,
+    retention: SeamBackupRetention,
+    staging: PathBuf,
+    lock: Arc<Mutex<()>>,
+}
+
+impl SeamBackupStore {
+    /// 将暂存的备份文件登记到备份清单。
+    fn commit_backup(&self, staging: &Path) -> Result<Option<SeamBackupEntry>, SeamProfileError> {
+        let digest = FileDigest::new(staging)?.to_string();
+        let mut manifest = self.load_manifest()?;
+        if manifest.backups.first().is_some_and(|e| e.digest == digest) {
+            debug!("当前配置与备份一致");
+            return Ok(None);
+        }
+
+        let now = SystemTime::now();
+        let file = format!(
+            "profiles-{}-{}.tar.gz",
+            chrono::DateTime::<chrono::Utc>::from(now).format("%Y%m%dT%H%M%SZ"),
+            &digest[..16]
+        );
+        let path = self.backup_path(&file);
+        std::fs::rename(staging, &path).map_err(|err| {
+            SeamProfileError::Backup(format!("写入 {} 失败：{}", path.display(), err))
+        })?;
+        let entry = SeamBackupEntry {
+            generation: manifest.next_generation,
+            file,
+            created: unix_secs(now),
+            digest,
+            size: path.metadata()?.len(),
+        };
+        manifest.next_generation += 1;
+        manifest.backups.insert(0, entry.clone());
+        self.apply_retention(&mut manifest, now);
+        self.save_manifest(&manifest)?;
+        info!("新建备份 #{} {}", entry.generation, path.display());
+        Ok(Some(entry))
+    }
+
+    /// 返回备份目录中指定文件的路径。
+    fn backup_path(&self, file: &str) -> PathBuf {
+        PathBuf::from(&self.backup_dir).join(file)
+    }
+
+    /// 按保留策略删除旧备份，最新的备份始终保留。
+    fn apply_retention(&self, manifest: &mut SeamBackupManifest, now: SystemTime) {
+        let policy = self.retention;
+        let now = unix_secs(now);
+        let mut kept: Vec<SeamBackupEntry> = Vec::with_capacity(manifest.backups.len());
+        let mut removed = Vec::new();
+        for (i, entry) in manifest.backups.drain(..).enumerate() {
+            let too_many = policy.max_count.is_some_and(|n| i >= n.max(1));
+            let too_old = policy
+                .max_age
+                .is_some_and(|age| i > 0 && now.saturating_sub(entry.created) > age.as_secs());
+            if too_many || too_old {
+                removed.push(entry);
+            } else {
+                kept.push(entry);
+            }
+        }
+        for entry in removed {
+            if kept.iter().any(|e| e.file == entry.file) {
+                continue;
+            }
+            let path = self.backup_path(&entry.file);
+            match std::fs::remove_file(&path) {
+                Ok(()) => info!("删除旧备份 #{} {}", entry.generation, path.display()),
+                Err(err) => warn!("删除旧备份 {} 失败：{}", path.display(), err),
+            }
+        }
+        manifest.backups = kept;
+    }
+
+    /// 加载备份清单，清单缺失或损坏时根据备份目录中的文件重建。
+    fn load_manifest(&self) -> Result<SeamBackupManifest, SeamProfileError> {
+        let path = self.backup_path(MANIFEST_NAME);
+        let mut manifest = match std::fs::read(&path) {
+            Ok(data) => match serde_json::from_slice::<SeamBackupManifest>(&data) {
+                Ok(manifest) => manifest,
+                Err(err) => {
+                    warn!("备份清单 {} 已损坏，重建清单：{}", path.display(), err);
+                    self.scan_backups()?
+                }
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => self.scan_backups()?,
+            Err(err) => return Err(err.into()),
+        };
+        manifest
+            .backups
+            .retain(|e| self.backup_path(&e.file).is_file());
+        Ok(manifest)
+    }
+
+    /// 根据备份目录中的文件生成备份清单，兼容旧版的 `profiles-N.tar.gz`。
+    fn scan_backups(&self) -> Result<SeamBackupManifest, SeamProfileError> {
+        let mut manifest = SeamBackupManifest::default();
+        for path in backup_tarballs(&self.backup_dir).into_iter().rev() {
+            let Some(file) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let meta = path.metadata()?;
+            manifest.backups.insert(
+                0,
+                SeamBackupEntry {
+                    generation: manifest.next_generation,
+                    file: file.into(),
+                    created: meta.modified().map_or(0, unix_secs),
+                    digest: FileDigest::new(&path)?.to_string(),
+                    size: meta.len(),
+                },
+            );
+            manifest.next_generation += 1;
+        }
+        Ok(manifest)
+    }
+
+    /// 保存备份清单。
+    fn save_manifest(&self, manifest: &SeamBackupManifest) -> Result<(), SeamProfileError> {
+        let text = serde_json::to_string_pretty(manifest)?;
+        write_atomic(self.backup_path(MANIFEST_NAME), text)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_backup_history() {
+        let dir = TestDir::new("backup");
+        let dir = dir.to_str();
+        let mut mgr =
+            SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir))
+                .unwrap();
+        mgr.backup_retention.max_count = Some(2);
+        mgr.save_all_profiles().unwrap();
+
+        let first = mgr.auto_backup().unwrap().unwrap();
+        assert_eq!(first.generation, 0);
+        assert!(first.file.contains(&first.digest[..16]));
+        assert!(mgr.auto_backup().unwrap().is_none());
+
+        for name in ["A", "B"] {
+            mgr.get_profile_mut(1).set_name(name);
+            mgr.save_profile(1).unwrap();
+            mgr.auto_backup().unwrap().unwrap();
+        }
+        let backups = mgr.list_backups().unwrap();
+        assert_eq!(
+            backups.iter().map(|e| e.generation).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert!(!mgr.backup_store().backup_path(&first.file).exists());
+        assert!(matches!(
+            mgr.inspect_backup(0),
+            Err(SeamProfileError::NoSuchBackup(0))
+        ));
+        assert_eq!(mgr.inspect_backup(1).unwrap()[1].name(), "A");
+
+        let diffs = mgr.restore_backup(1, true).unwrap();
+        assert_eq!(diffs[0].name, Some(("B".into(), "A".into())));
+        mgr.restore_backup(1, false).unwrap();
+        assert_eq!(mgr.get_profile(1).name(), "A");
+
+        std::fs::remove_file(mgr.backup_store().backup_path(MANIFEST_NAME)).unwrap();
+        assert_eq!(mgr.list_backups().unwrap().len(), 2);
+    }
+}
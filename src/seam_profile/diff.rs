@@ -0,0 +1,394 @@
+use super::{
+    SeamParamFlatId, SeamParamTypedValue, SeamParamsExtV1, SeamParamsV0, SeamProfile,
+    SeamProfileError, SeamProfileEvent, SeamProfileManager,
+};
+
+/// 一个代表单个参数变更的类型。
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SeamParamChange {
+    /// 参数编号。
+    pub id: SeamParamFlatId,
+    /// 原参数值。
+    pub old: SeamParamTypedValue,
+    /// 新参数值。
+    pub new: SeamParamTypedValue,
+}
+
+/// 一个代表单个 V1 版扩展参数变更的类型。
+///
+/// 扩展参数没有描述信息，以原始值表示。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamExtParamChange {
+    /// 参数在扩展参数表中的序号。
+    pub index: usize,
+    /// 原参数值。
+    pub old: i32,
+    /// 新参数值。
+    pub new: i32,
+}
+
+/// 一个代表两个配置之间差异的类型。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SeamProfileDiff {
+    /// 配置编号。
+    pub profile_id: usize,
+    /// 使能状态变更，依次为原值及新值。
+    pub enabled: Option<(bool, bool)>,
+    /// 名称变更，依次为原值及新值。
+    pub name: Option<(String, String)>,
+    /// 参数变更，按参数编号排列。
+    pub params: Vec<SeamParamChange>,
+    /// V1 版扩展参数变更，按序号排列。
+    pub ext: Vec<SeamExtParamChange>,
+}
+
+impl SeamProfileDiff {
+    /// 是否没有任何差异。
+    pub fn is_empty(&self) -> bool {
+        self.enabled.is_none()
+            && self.name.is_none()
+            && self.params.is_empty()
+            && self.ext.is_empty()
+    }
+}
+
+impl SeamParamsV0 {
+    /// 返回从当前参数表变为另一参数表的所有参数变更。
+    pub fn diff(&self, other: &SeamParamsV0) -> Vec<SeamParamChange> {
+        let old: &[i32] = self.as_ref();
+        let new: &[i32] = other.as_ref();
+        old.iter()
+            .zip(new)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .filter_map(|(i, _)| {
+                let id = SeamParamFlatId::from(i);
+                Some(SeamParamChange {
+                    id,
+                    old: self.get(id)?,
+                    new: other.get(id)?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl SeamParamsExtV1 {
+    /// 返回从当前扩展参数表变为另一扩展参数表的所有参数变更。
+    pub fn diff(&self, other: &SeamParamsExtV1) -> Vec<SeamExtParamChange> {
+        let old: &[i32] = self.as_ref();
+        let new: &[i32] = other.as_ref();
+        old.iter()
+            .zip(new)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(index, (&old, &new))| SeamExtParamChange { index, old, new })
+            .collect()
+    }
+}
+
+impl SeamProfile {
+    /// 返回从当前配置变为另一配置的差异。
+    pub fn diff(&self, other: &SeamProfile) -> SeamProfileDiff {
+        SeamProfileDiff {
+            profile_id: self.id() as usize,
+            enabled: (self.is_enabled() != other.is_enabled())
+                .then(|| (self.is_enabled(), other.is_enabled())),
+            name: (self.name() != other.name()).then(|| (self.name().into(), other.name().into())),
+            params: self.v0.diff(&other.v0),
+            ext: self.v1_ext.diff(&other.v1_ext),
+        }
+    }
+}
+
+/// 一个代表三方合并冲突的枚举，合并结果保留本地的值。
+#[derive(Clone, Debug, PartialEq)]
+pub enum SeamMergeConflict {
+    /// 使能状态冲突。
+    Enabled {
+        base: bool,
+        ours: bool,
+        theirs: bool,
+    },
+    /// 名称冲突。
+    Name {
+        base: String,
+        ours: String,
+        theirs: String,
+    },
+    /// 参数冲突。
+    Param {
+        id: SeamParamFlatId,
+        base: SeamParamTypedValue,
+        ours: SeamParamTypedValue,
+        theirs: SeamParamTypedValue,
+    },
+    /// V1 版扩展参数冲突。
+    ExtParam {
+        index: usize,
+        base: i32,
+        ours: i32,
+        theirs: i32,
+    },
+}
+
+/// 三方合并单个值，返回合并结果及是否冲突。
+fn merge3_value<T: PartialEq + Clone>(base: &T, ours: &T, theirs: &T) -> (T, bool) {
+    if theirs == base || ours == theirs {
+        (ours.clone(), false)
+    } else if ours == base {
+        (theirs.clone(), false)
+    } else {
+        (ours.clone(), true)
+    }
+}
+
+impl SeamParamsV0 {
+    /// 以 `base` 为共同祖先，将 `theirs` 的变更合并到当前参数表。
+    ///
+    /// 双方均修改且结果不同的参数视为冲突，保留当前值并返回冲突列表。
+    pub fn merge3(&mut self, base: &SeamParamsV0, theirs: &SeamParamsV0) -> Vec<SeamMergeConflict> {
+        let mut conflicts = Vec::new();
+        for change in base.diff(theirs) {
+            let Some(ours) = self.get(change.id) else {
+                continue;
+            };
+            let (value, conflict) =
+                merge3_value(&change.old.to_raw(), &ours.to_raw(), &change.new.to_raw());
+            if conflict {
+                conflicts.push(SeamMergeConflict::Param {
+                    id: change.id,
+                    base: change.old,
+                    ours,
+                    theirs: change.new,
+                });
+            } else {
+                unsafe { self.values[change.id as usize] = value };
+            }
+        }
+        conflicts
+    }
+}
+
+impl SeamParamsExtV1 {
+    /// 以 `base` 为共同祖先，将 `theirs` 的变更合并到当前扩展参数表，@see [`SeamParamsV0::merge3`]。
+    pub fn merge3(
+        &mut self,
+        base: &SeamParamsExtV1,
+        theirs: &SeamParamsExtV1,
+    ) -> Vec<SeamMergeConflict> {
+        let mut conflicts = Vec::new();
+        for change in base.diff(theirs) {
+            let values: &mut [i32] = self.as_mut();
+            let ours = values[change.index];
+            let (value, conflict) = merge3_value(&change.old, &ours, &change.new);
+            if conflict {
+                conflicts.push(SeamMergeConflict::ExtParam {
+                    index: change.index,
+                    base: change.old,
+                    ours,
+                    theirs: change.new,
+                });
+            } else {
+                values[change.index] = value;
+            }
+        }
+        conflicts
+    }
+}
+
+impl SeamProfile {
+    /// 以 `base` 为共同祖先，将 `theirs` 的使能状态、名称及参数变更合并到当前配置。
+    ///
+    /// 有变更时提交一次变更记录，冲突项保留当前值并返回冲突列表。
+    pub fn merge3(&mut self, base: &SeamProfile, theirs: &SeamProfile) -> Vec<SeamMergeConflict> {
+        let mut conflicts = Vec::new();
+        let (enabled, conflict) =
+            merge3_value(&base.is_enabled(), &self.is_enabled(), &theirs.is_enabled());
+        if conflict {
+            conflicts.push(SeamMergeConflict::Enabled {
+                base: base.is_enabled(),
+                ours: self.is_enabled(),
+                theirs: theirs.is_enabled(),
+            });
+        }
+        let (name, conflict) = merge3_value(&base.name(), &self.name(), &theirs.name());
+        let name = name.to_string();
+        if conflict {
+            conflicts.push(SeamMergeConflict::Name {
+                base: base.name().into(),
+                ours: self.name().into(),
+                theirs: theirs.name().into(),
+            });
+        }
+        let (old, old_ext) = (self.v0, self.v1_ext);
+        conflicts.extend(self.v0.merge3(&base.v0, &theirs.v0));
+        conflicts.extend(self.v1_ext.merge3(&base.v1_ext, &theirs.v1_ext));
+        if enabled != self.enabled
+            || name != self.name()
+            || !old.diff(&self.v0).is_empty()
+            || !old_ext.diff(&self.v1_ext).is_empty()
+        {
+            self.enabled = enabled;
+            self.meta.set_name(name);
+            self.sync_meta();
+            self.commit();
+        }
+        conflicts
+    }
+}
+
+impl SeamProfileManager {
+    /// 以 `base` 为共同祖先，将 `theirs` 的变更合并到编号相同的配置中。
+    ///
+    /// 合并结果未通过校验时不做任何修改；冲突项保留当前值并返回冲突列表。
+    pub fn merge_profile(
+        &mut self,
+        base: &SeamProfile,
+        theirs: &SeamProfile,
+    ) -> Result<Vec<SeamMergeConflict>, SeamProfileError> {
+        let id = theirs.id();
+        let current = self.try_get_profile(id as usize)?;
+        let mut merged = SeamProfile::new(current.is_enabled(), id);
+        merged.merge_with_commit(current);
+        let conflicts = merged.merge3(base, theirs);
+        self.check_profile(&merged)?;
+        self.update_profile(&merged);
+        Ok(conflicts)
+    }
+
+    /// 以新配置整体替换同编号的配置，提交一次变更并发送对应的变更事件。
+    pub(super) fn update_profile(&mut self, updated: &SeamProfile) -> SeamProfileDiff {
+        let id = updated.id() as usize;
+        let diff = self.profiles[id].diff(updated);
+        if diff.is_empty() {
+            return diff;
+        }
+        self.profiles[id].merge(updated);
+        self.profiles_ffi[id].enabled = updated.enabled as i32;
+        self.profiles_modified.set_now();
+        self.commit();
+        if let Some((_, enabled)) = diff.enabled {
+            self.emit(SeamProfileEvent::ProfileEnabled {
+                profile_id: id,
+                enabled,
+            });
+        }
+        for change in &diff.params {
+            self.emit(SeamProfileEvent::ParamChanged {
+                profile_id: id,
+                id: change.id,
+                old: change.old,
+                new: change.new,
+            });
+        }
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_diff() {
+        let a = SeamProfile::new(false, 3);
+        let mut b = SeamProfile::new(true, 3);
+        assert_eq!(
+            a.diff(&a),
+            SeamProfileDiff {
+                profile_id: 3,
+                ..Default::default()
+            }
+        );
+
+        b.set_name("Other");
+        b.v0.set(SeamParamFlatId::XpExposureTime, 1200);
+        let diff = a.diff(&b);
+        assert_eq!(diff.enabled, Some((false, true)));
+        assert_eq!(diff.name, Some(("".into(), "Other".into())));
+        assert_eq!(
+            diff.params,
+            vec![SeamParamChange {
+                id: SeamParamFlatId::XpExposureTime,
+                old: SeamParamTypedValue::I32(0),
+                new: SeamParamTypedValue::I32(1200),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_profile_merge3() {
+        let mut base = SeamProfile::new(false, 4);
+        base.v0.set(SeamParamFlatId::KpAngle1, 10.0f32);
+        let mut ours = SeamProfile::new(false, 4);
+        ours.merge_with_commit(&base);
+        let mut theirs = SeamProfile::new(true, 4);
+        theirs.merge_with_commit(&base);
+        theirs.set_enabled(true);
+
+        ours.set_name("Field");
+        ours.v0.set(SeamParamFlatId::KpAngle1, 20.0f32);
+        theirs.v0.set(SeamParamFlatId::KpAngle1, 30.0f32);
+        theirs.v0.set(SeamParamFlatId::XpExposureTime, 1200);
+
+        let conflicts = ours.merge3(&base, &theirs);
+        assert!(ours.is_enabled());
+        assert_eq!(ours.name(), "Field");
+        assert_eq!(
+            ours.v0.get(SeamParamFlatId::XpExposureTime),
+            Some(SeamParamTypedValue::I32(1200))
+        );
+        assert_eq!(
+            conflicts,
+            vec![SeamMergeConflict::Param {
+                id: SeamParamFlatId::KpAngle1,
+                base: SeamParamTypedValue::F32(10.0),
+                ours: SeamParamTypedValue::F32(20.0),
+                theirs: SeamParamTypedValue::F32(30.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_profile_ext_diff() {
+        let base = SeamProfile::new(true, 5);
+        let mut ours = SeamProfile::new(true, 5);
+        let mut theirs = SeamProfile::new(true, 5);
+        theirs.v1_ext.ap[0] = 7.into();
+        theirs.v1_ext.ap[1] = 8.into();
+        ours.v1_ext.ap[1] = 9.into();
+
+        let diff = base.diff(&theirs);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.ext,
+            vec![
+                SeamExtParamChange {
+                    index: 250,
+                    old: 0,
+                    new: 7
+                },
+                SeamExtParamChange {
+                    index: 251,
+                    old: 0,
+                    new: 8
+                },
+            ]
+        );
+
+        let conflicts = ours.merge3(&base, &theirs);
+        assert_eq!(ours.v1_ext.ap[0], 7.into());
+        assert_eq!(ours.v1_ext.ap[1], 9.into());
+        assert_eq!(
+            conflicts,
+            vec![SeamMergeConflict::ExtParam {
+                index: 251,
+                base: 0,
+                ours: 9,
+                theirs: 8,
+            }]
+        );
+        assert_eq!(SeamProfile::default().v1().version(), 1);
+    }
+}
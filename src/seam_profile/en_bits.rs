@@ -0,0 +1,277 @@
+use super::{
+    SeamParamArea, SeamParamFlatId, SeamParamKpId, SeamParamOcId, SeamParamOpId, SeamParamVpId,
+    SeamParamXpId, SeamParamsV0, SeamProfileError,
+};
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use SeamParamFlatId::*;
+
+/// 每个使能寄存器容纳的使能位数。
+const EN_WORD_BITS: usize = 30;
+
+/// 一个代表分区参数使能位集合的类型，第 n 位对应分区内索引为 n 的参数。
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SeamParamEnBits(pub u64);
+
+impl SeamParamEnBits {
+    /// 返回原始位值。
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// 指定索引的参数是否使能。
+    pub fn contains(self, index: usize) -> bool {
+        index < 64 && self.0 & (1 << index) != 0
+    }
+
+    /// 设置指定索引的参数是否使能，超出范围的索引将被忽略。
+    pub fn set(&mut self, index: usize, yes: bool) {
+        if index < 64 {
+            if yes {
+                self.0 |= 1 << index;
+            } else {
+                self.0 &= !(1 << index);
+            }
+        }
+    }
+
+    /// 使能的参数个数。
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// 是否没有使能任何参数。
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// 按顺序返回所有使能的参数索引。
+    pub fn iter(self) -> impl Iterator<Item = usize> {
+        (0..64).filter(move |&i| self.contains(i))
+    }
+}
+
+impl FromIterator<usize> for SeamParamEnBits {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut bits = Self::default();
+        for index in iter {
+            bits.set(index, true);
+        }
+        bits
+    }
+}
+
+impl SeamParamArea {
+    /// 返回存放本分区使能位的 SF 寄存器，低位在前。
+    pub fn en_ids(self) -> &'static [SeamParamFlatId] {
+        match self {
+            SeamParamArea::Xp => &[SfXpEn],
+            SeamParamArea::Kp => &[SfKpEn],
+            SeamParamArea::Op => &[SfOpEn1, SfOpEn2],
+            SeamParamArea::Vp => &[SfVpEn1, SfVpEn2],
+            SeamParamArea::Oc => &[SfOcEn1, SfOcEn2],
+            SeamParamArea::Sf => &[],
+        }
+    }
+}
+
+/// 所有带使能位的分区。
+const EN_AREAS: [SeamParamArea; 5] = [
+    SeamParamArea::Xp,
+    SeamParamArea::Kp,
+    SeamParamArea::Op,
+    SeamParamArea::Vp,
+    SeamParamArea::Oc,
+];
+
+/// 是否为存放使能位的 SF 寄存器。
+pub(super) fn is_en_id(id: SeamParamFlatId) -> bool {
+    EN_AREAS.iter().any(|a| a.en_ids().contains(&id))
+}
+
+impl SeamParamsV0 {
+    /// 返回分区的使能位集合，SF 区没有使能位。
+    ///
+    /// 除最后一个寄存器外，每个寄存器只使用低 30 位。
+    pub fn en_bits(&self, area: SeamParamArea) -> SeamParamEnBits {
+        let raw: &[i32] = self.as_ref();
+        let ids = area.en_ids();
+        let mut bits = 0u64;
+        for (i, &id) in ids.iter().enumerate() {
+            let word = raw[id as usize] as u32 as u64;
+            let word = if i + 1 < ids.len() {
+                word & 0x3FFF_FFFF
+            } else {
+                word
+            };
+            bits |= word << (i * EN_WORD_BITS);
+        }
+        SeamParamEnBits(bits)
+    }
+
+    /// 设置分区的使能位集合。
+    pub fn set_en_bits(&mut self, area: SeamParamArea, bits: SeamParamEnBits) {
+        let ids = area.en_ids();
+        let raw: &mut [i32] = self.as_mut();
+        for (i, &id) in ids.iter().enumerate() {
+            let word = bits.0 >> (i * EN_WORD_BITS);
+            let word = if i + 1 < ids.len() {
+                word & 0x3FFF_FFFF
+            } else {
+                word
+            };
+            raw[id as usize] = word as u32 as i32;
+        }
+    }
+
+    /// 指定参数是否使能，SF 区参数总是返回 `false`。
+    pub fn param_en(&self, id: SeamParamFlatId) -> bool {
+        match (id.area(), id.area_index()) {
+            (Some(area), Some(index)) => self.en_bits(area).contains(index),
+            _ => false,
+        }
+    }
+
+    /// 设置指定参数是否使能，SF 区参数将被忽略。
+    pub fn set_param_en(&mut self, id: SeamParamFlatId, yes: bool) {
+        if let (Some(area), Some(index)) = (id.area(), id.area_index()) {
+            let mut bits = self.en_bits(area);
+            bits.set(index, yes);
+            self.set_en_bits(area, bits);
+        }
+    }
+
+    /// 设置指定索引的 XP 参数开关。
+    pub fn set_xp_en(&mut self, index: SeamParamXpId, yes: bool) {
+        self.set_param_en(index.into(), yes);
+    }
+
+    /// 设置指定索引的 KP 参数开关。
+    pub fn set_kp_en(&mut self, index: SeamParamKpId, yes: bool) {
+        self.set_param_en(index.into(), yes);
+    }
+
+    /// 设置指定索引的 OP 参数开关。
+    pub fn set_op_en(&mut self, index: SeamParamOpId, yes: bool) {
+        self.set_param_en(index.into(), yes);
+    }
+
+    /// 设置指定索引的 VP 参数开关。
+    pub fn set_vp_en(&mut self, index: SeamParamVpId, yes: bool) {
+        self.set_param_en(index.into(), yes);
+    }
+
+    /// 设置指定索引的 OC 参数开关。
+    pub fn set_oc_en(&mut self, index: SeamParamOcId, yes: bool) {
+        self.set_param_en(index.into(), yes);
+    }
+
+    /// 按顺序返回分区内所有使能参数的平面空间编号。
+    pub fn enabled_ids(&self, area: SeamParamArea) -> impl Iterator<Item = SeamParamFlatId> {
+        self.en_bits(area)
+            .iter()
+            .filter(move |&i| i < area.count())
+            .map(move |i| area.flat_id(i))
+    }
+
+    /// 返回分区内所有使能参数的分区内名称，如 `ExposureTime`。
+    pub fn en_names(&self, area: SeamParamArea) -> Vec<&'static str> {
+        self.enabled_ids(area)
+            .filter_map(|id| id.desc())
+            .map(|desc| desc.area_name.as_str())
+            .collect()
+    }
+
+    /// 以分区内名称列表设置分区的使能位，未列出的参数均不使能。
+    pub fn set_en_names<S: AsRef<str>>(
+        &mut self,
+        area: SeamParamArea,
+        names: &[S],
+    ) -> Result<(), SeamProfileError> {
+        let bits = names
+            .iter()
+            .map(|name| {
+                let name = name.as_ref();
+                area.flat_ids()
+                    .find(|id| id.desc().is_some_and(|d| d.area_name == name))
+                    .and_then(|id| id.area_index())
+                    .ok_or_else(|| SeamProfileError::UnknownParam(format!("{}.{}", area, name)))
+            })
+            .collect::<Result<SeamParamEnBits, _>>()?;
+        self.set_en_bits(area, bits);
+        Ok(())
+    }
+
+    /// 从命名形式的使能列表中读取使能位，返回错误说明。
+    pub(super) fn read_named_en(&mut self, en: &Map<String, Value>) -> Result<(), String> {
+        for (key, value) in en {
+            let area = SeamParamArea::from_name(key)
+                .filter(|a| !a.en_ids().is_empty())
+                .ok_or_else(|| format!("未知的使能分区 {}", key))?;
+            let names: Vec<String> = serde_json::from_value(value.clone())
+                .map_err(|_| format!("分区 {} 的使能列表 {} 无效", key, value))?;
+            self.set_en_names(area, &names)
+                .map_err(|err| err.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// 以命名形式序列化所有分区的使能列表，如 `{"xp":["ExposureTime"],...}`。
+pub(super) struct SeamEnNamed<'a>(pub(super) &'a SeamParamsV0);
+
+impl Serialize for SeamEnNamed<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(EN_AREAS.len()))?;
+        for area in EN_AREAS {
+            map.serialize_entry(area.name(), &self.0.en_names(area))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SeamParamSfId, SeamParamsFormat, SeamProfile};
+
+    #[test]
+    fn test_en_bits() {
+        let mut v0 = SeamParamsV0::default();
+        v0.set_xp_en(SeamParamXpId::ExposureTime, true);
+        v0.set_op_en(SeamParamOpId::from(45), true);
+        assert_ne!(v0.xp_en(SeamParamXpId::ExposureTime), 0);
+        assert_eq!(v0.op_en(SeamParamOpId::from(45)), 1);
+        assert_eq!(v0.sf_i32(SeamParamSfId::OpEn2), 1 << 15);
+        assert_eq!(
+            v0.en_bits(SeamParamArea::Op).iter().collect::<Vec<_>>(),
+            [45]
+        );
+        v0.set_op_en(SeamParamOpId::from(45), false);
+        assert!(v0.en_bits(SeamParamArea::Op).is_empty());
+        assert_eq!(
+            v0.enabled_ids(SeamParamArea::Xp).collect::<Vec<_>>(),
+            [XpExposureTime]
+        );
+
+        v0.set_en_names(SeamParamArea::Kp, &["Angle1", "Gap1"])
+            .unwrap();
+        assert!(v0.param_en(KpGap1));
+        assert!(v0.set_en_names(SeamParamArea::Kp, &["Nope"]).is_err());
+
+        let mut profile = SeamProfile::new(true, 1);
+        profile.v0 = v0;
+        let value = profile.to_json_value(SeamParamsFormat::Named).unwrap();
+        assert_eq!(
+            value["v0"]["en"]["kp"],
+            serde_json::json!(["Angle1", "Gap1"])
+        );
+        assert!(value["v0"]["sf"].get("KpEn").is_none());
+        let loaded: SeamProfile = serde_json::from_value(value).unwrap();
+        assert!(profile.v0.diff(&loaded.v0).is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+use super::SeamParamViolation;
+use std::fmt;
+
+/// 一个代表接头识别配置错误的枚举。
+#[derive(Debug)]
+pub enum SeamProfileError {
+    /// 文件读写错误。
+    Io(std::io::Error),
+    /// JSON 解析或生成错误。
+    Json(serde_json::Error),
+    /// 无效的配置编号。
+    InvalidId(i32),
+    /// 配置不存在。
+    NoSuchProfile(i32),
+    /// 配置已存在。
+    ProfileExists(i32),
+    /// 配置正在使用中。
+    ProfileInUse(i32),
+    /// 没有空闲的配置槽位。
+    NoFreeSlot,
+    /// 未知的接头类型。
+    UnknownJointType(i32),
+    /// 接头类型没有内置模板。
+    NoSuchTemplate(i32),
+    /// 配置未通过校验。
+    Validation {
+        id: i32,
+        violations: Vec<SeamParamViolation>,
+    },
+    /// 档案规范不匹配。
+    SchemaMismatch { expected: String, found: String },
+    /// 未知的档案规范。
+    UnknownSchema(String),
+    /// 备份失败。
+    Backup(String),
+    /// 指定代号的备份不存在。
+    NoSuchBackup(u64),
+    /// 未知的参数名称。
+    UnknownParam(String),
+    /// 补丁无效或应用失败。
+    Patch(String),
+    /// 不支持的参数表版本。
+    UnsupportedVersion(i32),
+    /// 全局配置管理器已经初始化。
+    AlreadyInitialized,
+    /// 配置管理器实例已存在。
+    InstanceExists(String),
+}
+
+impl fmt::Display for SeamProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeamProfileError::Io(err) => write!(f, "文件读写错误：{}", err),
+            SeamProfileError::Json(err) => write!(f, "JSON 错误：{}", err),
+            SeamProfileError::InvalidId(id) => write!(f, "无效的配置编号 #{}", id),
+            SeamProfileError::NoSuchProfile(id) => write!(f, "配置 #{} 不存在", id),
+            SeamProfileError::ProfileExists(id) => write!(f, "配置 #{} 已存在", id),
+            SeamProfileError::ProfileInUse(id) => write!(f, "配置 #{} 正在使用中", id),
+            SeamProfileError::NoFreeSlot => write!(f, "没有空闲的配置槽位"),
+            SeamProfileError::UnknownJointType(value) => {
+                write!(f, "未知的接头类型 0x{:x}", value)
+            }
+            SeamProfileError::NoSuchTemplate(value) => {
+                write!(f, "接头类型 0x{:x} 没有内置模板", value)
+            }
+            SeamProfileError::Validation { id, violations } => {
+                write!(f, "配置 #{} 未通过校验", id)?;
+                for (i, v) in violations.iter().enumerate() {
+                    write!(f, "{}{}", if i == 0 { "：" } else { "；" }, v)?;
+                }
+                Ok(())
+            }
+            SeamProfileError::SchemaMismatch { expected, found } => {
+                write!(f, "档案规范不匹配，期望 {}，实际 {}", expected, found)
+            }
+            SeamProfileError::UnknownSchema(url) => write!(f, "未知的档案规范 {}", url),
+            SeamProfileError::Backup(reason) => write!(f, "备份失败：{}", reason),
+            SeamProfileError::NoSuchBackup(generation) => {
+                write!(f, "备份 #{} 不存在", generation)
+            }
+            SeamProfileError::UnknownParam(name) => write!(f, "未知的参数 {}", name),
+            SeamProfileError::Patch(reason) => write!(f, "补丁无效：{}", reason),
+            SeamProfileError::UnsupportedVersion(version) => {
+                write!(f, "不支持的参数表版本 {}", version)
+            }
+            SeamProfileError::AlreadyInitialized => write!(f, "全局配置管理器已经初始化"),
+            SeamProfileError::InstanceExists(name) => {
+                write!(f, "配置管理器实例 {} 已存在", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SeamProfileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SeamProfileError::Io(err) => Some(err),
+            SeamProfileError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SeamProfileError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for SeamProfileError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
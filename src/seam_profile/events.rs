@@ -0,0 +1,185 @@
+use super::{SeamParamFlatId, SeamParamTypedValue, SeamProfileManager, SeamReloadPolicy};
+#[cfg(feature = "async")]
+use tokio::sync::{broadcast, watch};
+
+/// 事件广播通道容量。
+#[cfg(feature = "async")]
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// 一个代表接头识别配置变更事件的枚举。
+#[derive(Clone, Debug, PartialEq)]
+pub enum SeamProfileEvent {
+    /// 当前配置已切换。
+    ProfileSwitched { old: usize, new: usize },
+    /// 配置参数已变更。
+    ParamChanged {
+        profile_id: usize,
+        id: SeamParamFlatId,
+        old: SeamParamTypedValue,
+        new: SeamParamTypedValue,
+    },
+    /// 配置已启用或禁用。
+    ProfileEnabled { profile_id: usize, enabled: bool },
+    /// 配置已创建或复制。
+    ProfileCreated { profile_id: usize },
+    /// 配置已删除。
+    ProfileDeleted { profile_id: usize },
+    /// 配置已从 JSON 或文件重新加载。
+    ProfilesReloaded { ids: Vec<usize> },
+    /// 配置已保存到文件。
+    Flushed { ids: Vec<usize> },
+    /// 配置未创建，对其所做的变更已丢弃。
+    ChangesDiscarded { profile_id: usize },
+    /// 配置文件在外部被修改，与未保存的变更冲突。
+    ExternalConflict {
+        profile_id: usize,
+        policy: SeamReloadPolicy,
+    },
+    /// 配置文件在外部被修改，但内容无效，未重新加载。
+    ExternalRejected { profile_id: usize, reason: String },
+}
+
+/// 一个代表配置变更回调函数的类型。
+pub type SeamProfileCallback = Box<dyn Fn(&SeamProfileEvent) + Send + Sync>;
+
+/// 一个代表配置变更订阅的类型，用于取消订阅。
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SeamProfileSubscription(u64);
+
+/// 配置变更事件分发器。
+pub(super) struct SeamProfileNotifier {
+    next_id: u64,
+    callbacks: Vec<(u64, SeamProfileCallback)>,
+    #[cfg(feature = "async")]
+    events: broadcast::Sender<SeamProfileEvent>,
+    #[cfg(feature = "async")]
+    current: watch::Sender<usize>,
+}
+
+impl SeamProfileNotifier {
+    pub(super) fn new() -> Self {
+        Self {
+            next_id: 0,
+            callbacks: Vec::new(),
+            #[cfg(feature = "async")]
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            #[cfg(feature = "async")]
+            current: watch::channel(0).0,
+        }
+    }
+
+    pub(super) fn emit(&self, event: SeamProfileEvent) {
+        for (_, cb) in &self.callbacks {
+            cb(&event);
+        }
+        #[cfg(feature = "async")]
+        {
+            if let SeamProfileEvent::ProfileSwitched { new, .. } = event {
+                self.current.send_replace(new);
+            }
+            let _ = self.events.send(event);
+        }
+    }
+}
+
+impl SeamProfileManager {
+    /// 注册配置变更回调函数。
+    ///
+    /// 回调函数在变更发生的线程中、持有管理器的情况下同步调用，
+    /// 切勿在回调中再次锁定管理器。
+    pub fn subscribe<F>(&mut self, callback: F) -> SeamProfileSubscription
+    where
+        F: Fn(&SeamProfileEvent) + Send + Sync + 'static,
+    {
+        let notifier = &mut self.notifier;
+        let id = notifier.next_id;
+        notifier.next_id += 1;
+        notifier.callbacks.push((id, Box::new(callback)));
+        SeamProfileSubscription(id)
+    }
+
+    /// 取消配置变更回调函数，返回是否找到对应的订阅。
+    pub fn unsubscribe(&mut self, subscription: SeamProfileSubscription) -> bool {
+        let callbacks = &mut self.notifier.callbacks;
+        let n = callbacks.len();
+        callbacks.retain(|(id, _)| *id != subscription.0);
+        callbacks.len() != n
+    }
+
+    /// 订阅配置变更事件广播。
+    #[cfg(feature = "async")]
+    pub fn subscribe_events(&self) -> broadcast::Receiver<SeamProfileEvent> {
+        self.notifier.events.subscribe()
+    }
+
+    /// 订阅当前配置编号。
+    #[cfg(feature = "async")]
+    pub fn watch_current_profile(&self) -> watch::Receiver<usize> {
+        self.notifier.current.subscribe()
+    }
+
+    /// 发送配置变更事件。
+    pub(super) fn emit(&self, event: SeamProfileEvent) {
+        self.notifier.emit(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_subscribe() {
+        let dir = TestDir::new("events");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events2 = events.clone();
+        let sub = mgr.subscribe(move |e| events2.lock().unwrap().push(e.clone()));
+
+        mgr.set_current_profile_id(2);
+        mgr.set_cur_v0_value_f32(SeamParamFlatId::KpAngle1, 45.0);
+        mgr.enable_profile(2);
+        mgr.enable_profile(2);
+        mgr.flush().unwrap();
+        assert!(mgr.unsubscribe(sub));
+        mgr.disable_profile(2);
+
+        let events = events.lock().unwrap();
+        assert_eq!(
+            *events,
+            vec![
+                SeamProfileEvent::ProfileSwitched { old: 0, new: 2 },
+                SeamProfileEvent::ParamChanged {
+                    profile_id: 2,
+                    id: SeamParamFlatId::KpAngle1,
+                    old: SeamParamTypedValue::F32(0.0),
+                    new: SeamParamTypedValue::F32(45.0),
+                },
+                SeamProfileEvent::ProfileEnabled {
+                    profile_id: 2,
+                    enabled: true,
+                },
+                SeamProfileEvent::Flushed { ids: vec![2] },
+            ]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_subscribe_events() {
+        let dir = TestDir::new("events-async");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        let mut events = mgr.subscribe_events();
+        let current = mgr.watch_current_profile();
+        mgr.set_current_profile_id(7);
+        assert_eq!(
+            events.try_recv().unwrap(),
+            SeamProfileEvent::ProfileSwitched { old: 0, new: 7 }
+        );
+        assert_eq!(*current.borrow(), 7);
+    }
+}
@@ -0,0 +1,182 @@
+use super::{SeamProfileError, SeamProfileEvent, SeamProfileManager, SeamProfileManagerLock};
+use log::{debug, error};
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+
+/// 一个代表待写入的配置文件的类型。
+pub(super) struct SeamFlushJob {
+    pub(super) id: usize,
+    pub(super) path: String,
+    pub(super) text: String,
+}
+
+impl SeamProfileManager {
+    /// 以较短的持锁时间保存有变更的配置，@see [`SeamProfileManager::flush`]。
+    ///
+    /// 仅在生成文件内容及替换文件时持有管理器锁，写入临时文件期间其他线程可正常访问管理器。
+    /// 须在异步运行时之外调用，异步任务请使用 `flush_async`。
+    pub fn flush_blocking(mgr: &SeamProfileManagerLock) -> Result<(), SeamProfileError> {
+        let flush_lock = mgr.lock_blocking().flush_lock.clone();
+        #[cfg(feature = "async")]
+        let _flushing = flush_lock.blocking_lock();
+        #[cfg(not(feature = "async"))]
+        let _flushing = flush_lock.lock().unwrap_or_else(|err| err.into_inner());
+
+        let (jobs, mut result) = match mgr.lock_blocking().prepare_flush() {
+            Some(prepared) => prepared,
+            None => return Ok(()),
+        };
+        let mut ids = Vec::new();
+        let mut failed = Vec::new();
+        for job in jobs {
+            match Self::write_profile_blocking(mgr, &job) {
+                Ok(true) => {
+                    debug!("配置 #{} 已经保存到 {}", job.id, job.path);
+                    ids.push(job.id);
+                }
+                Ok(false) => debug!("配置 #{} 已有更新的内容保存，放弃写入", job.id),
+                Err(err) => {
+                    error!("保存配置 #{} 失败：{}", job.id, err);
+                    failed.push(job.id);
+                    if result.is_ok() {
+                        result = Err(err.into());
+                    }
+                }
+            }
+        }
+        mgr.lock_blocking().finish_flush(ids, failed);
+        result
+    }
+
+    /// 取出有变更的配置的文件内容并清除变更记录，没有变更时返回 `None`。
+    ///
+    /// 返回的结果为生成文件内容时遇到的第一个错误。
+    pub(super) fn prepare_flush(
+        &self,
+    ) -> Option<(Vec<SeamFlushJob>, Result<(), SeamProfileError>)> {
+        if self.commits() == 0 {
+            return None;
+        }
+        self.clear_commits();
+        self.flush_times.fetch_add(1, Ordering::SeqCst);
+        let mut result = Ok(());
+        let mut jobs = Vec::new();
+        for (i, p) in self.profiles.iter().enumerate() {
+            if p.commits() == 0 {
+                continue;
+            }
+            if !self.existing[i] {
+                p.flush();
+                self.discard_changes(i);
+                continue;
+            }
+            match self.profile_text(i) {
+                Ok(text) => {
+                    p.flush();
+                    self.mark_saved(i, &text);
+                    jobs.push(SeamFlushJob {
+                        id: i,
+                        path: self.profile_path(i),
+                        text,
+                    });
+                }
+                Err(err) => {
+                    error!("保存配置 #{} 失败：{}", i, err);
+                    self.commits.fetch_add(1, Ordering::SeqCst);
+                    if result.is_ok() {
+                        result = Err(err);
+                    }
+                }
+            }
+        }
+        Some((jobs, result))
+    }
+
+    /// 恢复写入失败的配置的变更记录，并通知已保存的配置。
+    pub(super) fn finish_flush(&self, ids: Vec<usize>, failed: Vec<usize>) {
+        for i in failed {
+            self.profiles[i].commit();
+            self.commits.fetch_add(1, Ordering::SeqCst);
+        }
+        if !ids.is_empty() {
+            self.emit(SeamProfileEvent::Flushed { ids });
+        }
+    }
+
+    /// 以原子方式写入配置文件，返回是否写入。
+    ///
+    /// 内容先写入临时文件，再持有管理器锁确认内容仍为最近一次保存的内容后替换目标文件，
+    /// 否则删除临时文件并返回 `false`。
+    fn write_profile_blocking(
+        mgr: &SeamProfileManagerLock,
+        job: &SeamFlushJob,
+    ) -> io::Result<bool> {
+        let path = Path::new(&job.path);
+        let mut tmp_name = OsString::from(".");
+        tmp_name.push(path.file_name().unwrap_or_default());
+        tmp_name.push(".flush.tmp");
+        let tmp_path = path.with_file_name(tmp_name);
+
+        let r = (|| {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(job.text.as_bytes())?;
+            file.sync_all()?;
+            let mgr = mgr.lock_blocking();
+            if !mgr.is_saved(job.id, &job.text) {
+                return Ok(false);
+            }
+            std::fs::rename(&tmp_path, path)?;
+            Ok(true)
+        })();
+        if !matches!(r, Ok(true)) {
+            let _ = std::fs::remove_file(&tmp_path);
+            return r;
+        }
+
+        // 同步目录项，确保重命名操作落盘。
+        #[cfg(unix)]
+        if let Some(dir) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            File::open(dir)?.sync_all()?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_flush_blocking() {
+        let dir = TestDir::new("flush");
+        let dir = dir.to_str();
+        let mgr = SeamProfileManagerLock::new(SeamProfileManager::new(
+            format!("{}/backup", dir),
+            format!("{}/config", dir),
+        ));
+        let path = {
+            let mut mgr = mgr.lock_blocking();
+            mgr.load_all_profiles().unwrap();
+            mgr.enable_profile(2);
+            mgr.rename_profile(2, "Blocking").unwrap();
+            mgr.profile_path(2)
+        };
+        SeamProfileManager::flush_blocking(&mgr).unwrap();
+        assert!(std::fs::read_to_string(&path).unwrap().contains("Blocking"));
+        assert_eq!(mgr.lock_blocking().commits(), 0);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        mgr.lock_blocking().mark_saved(2, "newer");
+        let job = SeamFlushJob {
+            id: 2,
+            path: path.clone(),
+            text: "stale".into(),
+        };
+        assert!(!SeamProfileManager::write_profile_blocking(&mgr, &job).unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+    }
+}
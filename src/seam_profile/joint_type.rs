@@ -0,0 +1,217 @@
+use super::{SeamParamFlatId, SeamParamsV0, SeamProfileError, SeamProfileManager, SeamProfileMeta};
+use std::fmt;
+
+/// 一个代表接头类型的枚举，与 `dwl3::WELDTYPE` 一一对应。
+///
+/// 编码的高 4 位为主要类型，低 4 位为次要类型，如 V 型坡口为 `0x31`；
+/// SF 区中以主要类型（高 8 位）及次要类型（低 8 位）存放，如 V 型坡口为 `0x0301`。
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum JointType {
+    NLeft = 0x10,
+    NRight = 0x11,
+    Butt = 0x20,
+    M = 0x30,
+    GrooveV = 0x31,
+    KLeft = 0x32,
+    KRight = 0x33,
+    InFillet = 0x40,
+    OutFillet = 0x41,
+    RippleLeft = 0x42,
+    RippleRight = 0x43,
+    Line = 0x50,
+    LineCircle = 0x60,
+    CircleLine = 0x61,
+    Circle = 0x70,
+    CircleTop = 0x80,
+    SolderJoint = 0x90,
+}
+
+impl JointType {
+    /// 所有接头类型，按编码排列。
+    pub const ALL: [JointType; 17] = [
+        JointType::NLeft,
+        JointType::NRight,
+        JointType::Butt,
+        JointType::M,
+        JointType::GrooveV,
+        JointType::KLeft,
+        JointType::KRight,
+        JointType::InFillet,
+        JointType::OutFillet,
+        JointType::RippleLeft,
+        JointType::RippleRight,
+        JointType::Line,
+        JointType::LineCircle,
+        JointType::CircleLine,
+        JointType::Circle,
+        JointType::CircleTop,
+        JointType::SolderJoint,
+    ];
+
+    /// 返回 `dwl3::WELDTYPE` 编码。
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+
+    /// 以 `dwl3::WELDTYPE` 编码查找接头类型。
+    pub fn from_code(code: i32) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.code() == code)
+    }
+
+    /// 返回主要类型。
+    pub fn major(self) -> i32 {
+        self.code() >> 4
+    }
+
+    /// 返回次要类型。
+    pub fn minor(self) -> i32 {
+        self.code() & 0xf
+    }
+
+    /// 返回 SF 区中的存放值。
+    pub fn to_raw(self) -> i32 {
+        (self.major() << 8) | self.minor()
+    }
+
+    /// 从 SF 区中的存放值解码接头类型。
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        let (major, minor) = ((raw >> 8) & 0xff, raw & 0xff);
+        if major > 0xf || minor > 0xf {
+            return None;
+        }
+        Self::from_code((major << 4) | minor)
+    }
+
+    /// 返回 `dwl3::WELDTYPE` 中的名称，如 `GROOVE_V`。
+    pub fn name(self) -> &'static str {
+        match self {
+            JointType::NLeft => "N_LEFT",
+            JointType::NRight => "N_RIGHT",
+            JointType::Butt => "BUTT",
+            JointType::M => "M",
+            JointType::GrooveV => "GROOVE_V",
+            JointType::KLeft => "K_LEFT",
+            JointType::KRight => "K_RIGHT",
+            JointType::InFillet => "IN_FILLET",
+            JointType::OutFillet => "OUT_FILLET",
+            JointType::RippleLeft => "RIPPLE_LEFT",
+            JointType::RippleRight => "RIPPLE_RIGHT",
+            JointType::Line => "LINE",
+            JointType::LineCircle => "LINE_CIRCLE",
+            JointType::CircleLine => "CIRCLE_LINE",
+            JointType::Circle => "CIRCLE",
+            JointType::CircleTop => "CIRCLE_TOP",
+            JointType::SolderJoint => "SOLDER_JOINT",
+        }
+    }
+
+    /// 以名称查找接头类型。
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    /// 返回显示名称。
+    pub fn label(self) -> &'static str {
+        match self {
+            JointType::NLeft => "N 型左",
+            JointType::NRight => "N 型右",
+            JointType::Butt => "对接",
+            JointType::M => "M 型",
+            JointType::GrooveV => "V 型坡口",
+            JointType::KLeft => "K 型坡口左",
+            JointType::KRight => "K 型坡口右",
+            JointType::InFillet => "内角焊",
+            JointType::OutFillet => "外角焊",
+            JointType::RippleLeft => "波纹板左弧",
+            JointType::RippleRight => "波纹板右弧",
+            JointType::Line => "直线",
+            JointType::LineCircle => "直线圆",
+            JointType::CircleLine => "圆直线",
+            JointType::Circle => "圆检测",
+            JointType::CircleTop => "顶点",
+            JointType::SolderJoint => "焊点检测",
+        }
+    }
+}
+
+impl fmt::Display for JointType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl TryFrom<i32> for JointType {
+    type Error = SeamProfileError;
+
+    /// 从 `dwl3::WELDTYPE` 编码转换。
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::from_code(value).ok_or(SeamProfileError::UnknownJointType(value))
+    }
+}
+
+impl SeamParamsV0 {
+    /// 返回接头类型，未设置或无法识别时返回 `None`。
+    pub fn typed_joint_type(&self) -> Option<JointType> {
+        JointType::from_raw(self.joint_type())
+    }
+
+    /// 设置接头类型。
+    pub fn set_typed_joint_type(&mut self, value: JointType) {
+        self.set(SeamParamFlatId::SfJointType, value.to_raw());
+    }
+}
+
+impl SeamProfileMeta {
+    /// 返回接头类型，未设置或无法识别时返回 `None`。
+    pub fn typed_joint_type(&self) -> Option<JointType> {
+        JointType::from_raw(self.joint_type)
+    }
+
+    /// 设置接头类型。
+    pub fn set_typed_joint_type(&mut self, value: JointType) {
+        self.set_joint_type(value.to_raw());
+    }
+}
+
+impl SeamProfileManager {
+    /// 返回指定接头类型的所有已创建配置的编号。
+    pub fn profiles_by_joint_type(&self, joint_type: JointType) -> Vec<usize> {
+        self.profile_ids()
+            .into_iter()
+            .filter(|&id| self.profiles[id].v0.typed_joint_type() == Some(joint_type))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_joint_type() {
+        for t in JointType::ALL {
+            assert_eq!(JointType::from_raw(t.to_raw()), Some(t));
+            assert_eq!(JointType::from_name(t.name()), Some(t));
+        }
+        let t = JointType::GrooveV;
+        assert_eq!((t.major(), t.minor(), t.to_raw()), (3, 1, 0x0301));
+        assert_eq!(t.to_string(), "V 型坡口");
+        assert!(JointType::try_from(0x34).is_err());
+        assert_eq!(JointType::from_raw(0), None);
+
+        let mut v0 = SeamParamsV0::with_defaults();
+        let strict = crate::SeamValidateOptions {
+            require_joint_type: true,
+            ..Default::default()
+        };
+        assert_eq!(v0.validate_with(&strict).len(), 1);
+        v0.set_typed_joint_type(JointType::InFillet);
+        assert!(v0.validate_with(&strict).is_empty());
+        assert_eq!(v0.joint_type_major(), 4);
+        assert_eq!(v0.typed_joint_type(), Some(JointType::InFillet));
+        let mut meta = SeamProfileMeta::default();
+        meta.set_typed_joint_type(JointType::InFillet);
+        assert_eq!(meta.joint_type, v0.joint_type());
+    }
+}
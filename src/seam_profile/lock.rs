@@ -0,0 +1,239 @@
+use super::{Mutex, SeamProfileManager};
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::{MutexGuard, TryLockError};
+use std::task::{Context, Poll, Waker};
+
+/// 等待锁定配置管理器的异步任务，以管理器锁的地址区分。
+static LOCK_WAITERS: Mutex<Vec<(usize, Waker)>> = Mutex::new(Vec::new());
+
+/// 一个代表共享的配置管理器的类型。
+///
+/// 内部的互斥锁不对外公开，只能通过 [`SeamProfileManagerLock::lock_blocking`] 或
+/// [`SeamProfileManagerLock::lock_async`] 锁定，释放时总会唤醒等待锁定的异步任务。
+pub struct SeamProfileManagerLock {
+    inner: Mutex<SeamProfileManager>,
+}
+
+impl SeamProfileManagerLock {
+    pub fn new(mgr: SeamProfileManager) -> Self {
+        Self {
+            inner: Mutex::new(mgr),
+        }
+    }
+
+    /// 以阻塞方式锁定配置管理器，可在任意线程中调用，包括异步运行时的工作线程。
+    ///
+    /// 其他线程持锁时发生 panic 不影响锁定。
+    pub fn lock_blocking(&self) -> SeamProfileGuard<'_> {
+        SeamProfileGuard {
+            mgr: self,
+            guard: Some(self.inner.lock().unwrap_or_else(|err| err.into_inner())),
+        }
+    }
+
+    /// 以异步方式锁定配置管理器。
+    ///
+    /// 返回的锁不能跨越 `.await` 持有，耗时的文件读写请使用 `*_async` 系列方法。
+    pub fn lock_async(&self) -> SeamProfileLockFuture<'_> {
+        SeamProfileLockFuture { mgr: self }
+    }
+
+    /// 尝试锁定配置管理器，已被占用时返回 `None`。
+    pub fn try_lock(&self) -> Option<SeamProfileGuard<'_>> {
+        let guard = match self.inner.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(err)) => err.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+        Some(SeamProfileGuard {
+            mgr: self,
+            guard: Some(guard),
+        })
+    }
+
+    /// 返回管理器锁的地址，用于区分等待的异步任务。
+    fn addr(&self) -> usize {
+        self as *const Self as usize
+    }
+}
+
+impl From<SeamProfileManager> for SeamProfileManagerLock {
+    fn from(mgr: SeamProfileManager) -> Self {
+        Self::new(mgr)
+    }
+}
+
+/// 一个代表已锁定的配置管理器的类型，释放时唤醒等待锁定的异步任务。
+pub struct SeamProfileGuard<'a> {
+    mgr: &'a SeamProfileManagerLock,
+    guard: Option<MutexGuard<'a, SeamProfileManager>>,
+}
+
+impl Deref for SeamProfileGuard<'_> {
+    type Target = SeamProfileManager;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for SeamProfileGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.as_mut().unwrap()
+    }
+}
+
+impl Drop for SeamProfileGuard<'_> {
+    fn drop(&mut self) {
+        drop(self.guard.take());
+        let addr = self.mgr.addr();
+        let mut waiters = LOCK_WAITERS.lock().unwrap_or_else(|err| err.into_inner());
+        let mut i = 0;
+        while i < waiters.len() {
+            if waiters[i].0 == addr {
+                waiters.swap_remove(i).1.wake();
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// 一个代表以异步方式锁定配置管理器的 Future 类型。
+///
+/// 管理器被占用时登记唤醒器并让出执行权，持锁方释放 [`SeamProfileGuard`] 时唤醒，
+/// 不会阻塞异步运行时的工作线程，也不依赖特定的异步运行时。
+pub struct SeamProfileLockFuture<'a> {
+    mgr: &'a SeamProfileManagerLock,
+}
+
+impl<'a> Future for SeamProfileLockFuture<'a> {
+    type Output = SeamProfileGuard<'a>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(guard) = self.mgr.try_lock() {
+            return Poll::Ready(guard);
+        }
+        let addr = self.mgr.addr();
+        {
+            let mut waiters = LOCK_WAITERS.lock().unwrap_or_else(|err| err.into_inner());
+            match waiters
+                .iter_mut()
+                .find(|(a, w)| *a == addr && w.will_wake(cx.waker()))
+            {
+                Some((_, waker)) => waker.clone_from(cx.waker()),
+                None => waiters.push((addr, cx.waker().clone())),
+            }
+        }
+        // 登记期间持锁方可能已经释放，再尝试一次以免错过唤醒。
+        match self.mgr.try_lock() {
+            Some(guard) => Poll::Ready(guard),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl SeamProfileManager {
+    /// 以阻塞方式锁定配置管理器，@see [`SeamProfileManagerLock::lock_blocking`]。
+    pub fn lock_blocking(mgr: &SeamProfileManagerLock) -> SeamProfileGuard<'_> {
+        mgr.lock_blocking()
+    }
+
+    /// 以异步方式锁定配置管理器，@see [`SeamProfileManagerLock::lock_async`]。
+    pub fn lock_async(mgr: &SeamProfileManagerLock) -> SeamProfileLockFuture<'_> {
+        mgr.lock_async()
+    }
+
+    /// 以阻塞方式锁定全局配置管理器，@see [`SeamProfileManager::lock_blocking`]。
+    pub fn global_blocking() -> SeamProfileGuard<'static> {
+        Self::lock_blocking(Self::global())
+    }
+
+    /// 以异步方式锁定全局配置管理器，@see [`SeamProfileManager::lock_async`]。
+    pub fn global_async() -> SeamProfileLockFuture<'static> {
+        Self::lock_async(Self::global())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::{fv_spmh_cur_profile_id, SeamProfileManagerHandle};
+    use std::sync::Arc;
+    use std::task::Wake;
+    use std::time::Duration;
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// 在当前线程中执行 Future 直至完成。
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = std::pin::pin!(fut);
+        let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                return output;
+            }
+            std::thread::park();
+        }
+    }
+
+    #[test]
+    fn test_lock_async() {
+        let dir = TestDir::new("lock");
+        let dir = dir.to_str();
+        let mgr = Arc::new(SeamProfileManagerLock::new(SeamProfileManager::new(
+            format!("{}/backup", dir),
+            format!("{}/config", dir),
+        )));
+        let guard = SeamProfileManager::lock_blocking(&mgr);
+        let locker = {
+            let mgr = mgr.clone();
+            std::thread::spawn(move || block_on(SeamProfileManager::lock_async(&mgr)).slots())
+        };
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(!locker.is_finished());
+        drop(guard);
+        assert_eq!(locker.join().unwrap(), 256);
+
+        let handle = SeamProfileManagerHandle::new(mgr.clone());
+        let spm = &handle as *const _ as *mut _;
+        let id = block_on(async {
+            let mut mgr = SeamProfileManager::lock_async(&mgr).await;
+            mgr.switch_profile(2).unwrap();
+            drop(mgr);
+            unsafe { fv_spmh_cur_profile_id(spm) }
+        });
+        assert_eq!(id, 2);
+        let generation = handle.publisher().generation();
+        assert_eq!(unsafe { fv_spmh_cur_profile_id(spm) }, 2);
+        assert_eq!(handle.publisher().generation(), generation);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_ffi_in_runtime() {
+        let dir = TestDir::new("lock-runtime");
+        let dir = dir.to_str();
+        let mgr = Arc::new(SeamProfileManagerLock::new(SeamProfileManager::new(
+            format!("{}/backup", dir),
+            format!("{}/config", dir),
+        )));
+        let handle = SeamProfileManagerHandle::new(mgr);
+        let spm = &handle as *const _ as *mut _;
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let id = rt.block_on(async { unsafe { fv_spmh_cur_profile_id(spm) } });
+        assert_eq!(id, 0);
+    }
+}
@@ -0,0 +1,101 @@
+use super::{JointType, SeamParamFlatId, SeamProfile};
+use log::warn;
+
+impl SeamProfile {
+    /// 元数据中的接头类型及版本是否与 SF 区一致。
+    pub fn is_meta_consistent(&self) -> bool {
+        let joint_type = self.v0.joint_type();
+        self.meta.joint_type == joint_type
+            && self.meta.joint_type_major == joint_type >> 8
+            && self.meta.joint_type_minor == joint_type & 0xff
+            && self.meta.version == self.v0.version()
+    }
+
+    /// 以 SF 区为准同步元数据中的接头类型及版本。
+    pub fn sync_meta(&mut self) {
+        self.meta.set_joint_type(self.v0.joint_type());
+        self.meta.version = self.v0.version();
+    }
+
+    /// 设置接头类型，同时写入 SF 区及元数据。
+    pub fn set_joint_type(&mut self, value: i32) {
+        self.v0.set(SeamParamFlatId::SfJointType, value & 0xffff);
+        self.sync_meta();
+        self.commit();
+    }
+
+    /// 设置接头类型，同时写入 SF 区及元数据。
+    pub fn set_typed_joint_type(&mut self, value: JointType) {
+        self.set_joint_type(value.to_raw());
+    }
+
+    /// 加载时协调元数据与 SF 区，返回两者是否曾不一致。
+    ///
+    /// SF 区未设置接头类型而元数据已设置时以元数据为准，其余情况均以 SF 区为准。
+    pub(super) fn reconcile_meta(&mut self) -> bool {
+        if self.is_meta_consistent() {
+            return false;
+        }
+        let meta = &self.meta;
+        let meta_joint_type = match meta.joint_type {
+            0 => (meta.joint_type_major << 8) | (meta.joint_type_minor & 0xff),
+            value => value,
+        } & 0xffff;
+        warn!(
+            "配置 #{} 元数据与 SF 区不一致：接头类型 0x{:04x}/0x{:04x}，版本 {}/{}",
+            self.id,
+            meta_joint_type,
+            self.v0.joint_type(),
+            meta.version,
+            self.v0.version()
+        );
+        if self.v0.joint_type() == 0 && meta_joint_type != 0 {
+            self.v0.set(SeamParamFlatId::SfJointType, meta_joint_type);
+        }
+        self.sync_meta();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::SeamProfileManager;
+
+    #[test]
+    fn test_meta_sync() {
+        let mut profile = SeamProfile::new(true, 1);
+        assert!(profile.is_meta_consistent());
+        profile.set_v0_value_i32(SeamParamFlatId::SfVersion, 3);
+        assert_eq!(profile.meta().version, 3);
+
+        profile.set_v0_value_i32(SeamParamFlatId::SfJointType, 0x0401);
+        assert_eq!(profile.meta().joint_type_major, 4);
+        profile.set_typed_joint_type(JointType::GrooveV);
+        assert_eq!(profile.v0.typed_joint_type(), Some(JointType::GrooveV));
+        assert_eq!(profile.meta().typed_joint_type(), Some(JointType::GrooveV));
+
+        let mut json = serde_json::to_value(&profile).unwrap();
+        json["v0"]["values"][240] = 0.into();
+        json["meta"]["jointType"] = JointType::Butt.to_raw().into();
+        json["meta"]["version"] = 7.into();
+        let dir = TestDir::new("meta-sync");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        mgr.load_profile_from_json_str(&json.to_string()).unwrap();
+        let loaded = mgr.get_profile(1);
+        assert!(loaded.is_meta_consistent());
+        assert_eq!(loaded.v0.typed_joint_type(), Some(JointType::Butt));
+        assert_eq!(loaded.meta().version, 3);
+        assert_eq!(loaded.v1().version(), 1);
+
+        mgr.set_current_profile_id(1);
+        mgr.set_cur_v0(SeamParamFlatId::SfJointType, JointType::Line.to_raw());
+        let value = mgr
+            .current_profile()
+            .to_json_value(Default::default())
+            .unwrap();
+        assert_eq!(value["meta"]["jointType"], JointType::Line.to_raw());
+    }
+}
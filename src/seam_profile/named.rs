@@ -0,0 +1,178 @@
+use super::en_bits::{is_en_id, SeamEnNamed};
+use super::{seam_param_descs, SeamParamArea, SeamParamsV0, SeamProfile};
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// 一个代表参数表 JSON 表示形式的枚举。
+///
+/// 加载时自动识别两种形式。
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SeamParamsFormat {
+    /// 原始数值数组形式 `{"values":[...]}`，浮点数以位模式表示。
+    #[default]
+    Values,
+    /// 按分区分组的命名形式 `{"xp":{"ExposureTime":1000,...},...}`，
+    /// 使能位以参数名称列表表示，如 `"en":{"xp":["ExposureTime"],...}`。
+    Named,
+}
+
+/// 一个代表以命名形式序列化参数表的类型。
+///
+/// 值为零的保留参数不输出，SF 区的使能寄存器以 `en` 中的名称列表代替。
+pub struct SeamParamsV0Named<'a>(pub &'a SeamParamsV0);
+
+/// 以命名形式序列化单个分区。
+struct SeamAreaNamed<'a>(&'a SeamParamsV0, SeamParamArea);
+
+impl Serialize for SeamAreaNamed<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let raw: &[i32] = self.0.as_ref();
+        let mut map = serializer.serialize_map(None)?;
+        for id in self.1.flat_ids() {
+            let Some(desc) = id.desc() else {
+                continue;
+            };
+            if (desc.reserved && raw[id as usize] == 0) || is_en_id(id) {
+                continue;
+            }
+            if let Some(value) = self.0.get(id) {
+                map.serialize_entry(&desc.area_name, &desc.to_json(value))?;
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for SeamParamsV0Named<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(SeamParamArea::ALL.len() + 1))?;
+        for area in SeamParamArea::ALL {
+            map.serialize_entry(area.name(), &SeamAreaNamed(self.0, area))?;
+        }
+        map.serialize_entry("en", &SeamEnNamed(self.0))?;
+        map.end()
+    }
+}
+
+impl SeamParamsV0 {
+    /// 返回以命名形式序列化的包装。
+    pub fn named(&self) -> SeamParamsV0Named<'_> {
+        SeamParamsV0Named(self)
+    }
+
+    /// 从命名形式的分区数据中读取参数，返回错误说明。
+    pub(super) fn read_named_area(
+        &mut self,
+        area: SeamParamArea,
+        params: &Map<String, Value>,
+    ) -> Result<(), String> {
+        for (key, value) in params {
+            let desc = seam_param_descs()
+                .iter()
+                .find(|d| d.area == area && d.area_name == *key)
+                .ok_or_else(|| format!("未知的参数 {}.{}", area, key))?;
+            let typed = desc
+                .parse_json(value)
+                .ok_or_else(|| format!("参数 {}.{} 的值 {} 无效", area, key, value))?;
+            self.set(desc.id, typed);
+        }
+        Ok(())
+    }
+}
+
+impl SeamProfile {
+    /// 按指定的参数表形式转换为 JSON 值，元数据中的接头类型及版本以 SF 区为准。
+    pub fn to_json_value(&self, format: SeamParamsFormat) -> serde_json::Result<Value> {
+        let mut value = serde_json::to_value(self)?;
+        if !self.is_meta_consistent() {
+            let mut meta = self.meta.clone();
+            meta.set_joint_type(self.v0.joint_type());
+            meta.version = self.v0.version();
+            value["meta"] = serde_json::to_value(meta)?;
+        }
+        if format == SeamParamsFormat::Named {
+            value["v0"] = serde_json::to_value(self.v0.named())?;
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::{SeamParamFlatId, SeamParamTypedValue};
+
+    #[test]
+    fn test_named_format() {
+        let mut profile = SeamProfile::new(true, 7);
+        profile.v0.set(SeamParamFlatId::KpAngle1, 60.5f32);
+        profile.v0.set(SeamParamFlatId::XpExposureTime, 1200);
+        profile.v0.set(SeamParamFlatId::SfXpEn, 3);
+        let value = profile.to_json_value(SeamParamsFormat::Named).unwrap();
+        assert_eq!(value["v0"]["kp"]["Angle1"], serde_json::json!(60.5));
+        assert_eq!(value["v0"]["xp"]["ExposureTime"], serde_json::json!(1200));
+        assert_eq!(
+            value["v0"]["en"]["xp"],
+            serde_json::json!(["ExposureControl", "ExposureFramerate"])
+        );
+
+        let loaded: SeamProfile = serde_json::from_value(value).unwrap();
+        assert!(profile.v0.diff(&loaded.v0).is_empty());
+
+        let legacy = profile.to_json_value(SeamParamsFormat::Values).unwrap();
+        assert!(legacy["v0"]["values"].is_array());
+        let loaded: SeamProfile = serde_json::from_value(legacy).unwrap();
+        assert_eq!(
+            loaded.v0.get(SeamParamFlatId::KpAngle1),
+            Some(SeamParamTypedValue::F32(60.5))
+        );
+
+        let bad = serde_json::json!({"xp": {"ExposureTimes": 1}});
+        assert!(serde_json::from_value::<SeamParamsV0>(bad).is_err());
+    }
+
+    #[test]
+    fn test_named_non_finite() {
+        let mut profile = SeamProfile::new(true, 3);
+        profile.v0.set_value_i32(SeamParamFlatId::OcBasePosY, -1);
+        profile.v0.set(SeamParamFlatId::OcBaseDeltaY, f32::INFINITY);
+        profile
+            .v0
+            .set(SeamParamFlatId::OcBasePosZ, f32::NEG_INFINITY);
+        profile.v0.set(SeamParamFlatId::KpAngle1, f32::NAN);
+        let text = serde_json::to_string(&profile.to_json_value(SeamParamsFormat::Named).unwrap())
+            .unwrap();
+        let loaded: SeamProfile = serde_json::from_str(&text).unwrap();
+        let raw: &[i32] = profile.v0.as_ref();
+        let loaded_raw: &[i32] = loaded.v0.as_ref();
+        assert_eq!(raw, loaded_raw);
+    }
+
+    #[test]
+    fn test_save_named() {
+        let dir = TestDir::new("named");
+        let dir = dir.to_str();
+        let mut mgr =
+            crate::SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        mgr.params_format = SeamParamsFormat::Named;
+        mgr.get_profile_mut(2)
+            .v0
+            .set(SeamParamFlatId::KpAngle1, 45.0f32);
+        mgr.save_profile(2).unwrap();
+        let text = std::fs::read_to_string(mgr.profile_path(2)).unwrap();
+        assert!(text.contains("\"Angle1\": 45.0"));
+        let loaded = mgr.load_profile(2).unwrap();
+        assert_eq!(
+            loaded.v0.get(SeamParamFlatId::KpAngle1),
+            Some(SeamParamTypedValue::F32(45.0))
+        );
+    }
+}
@@ -0,0 +1,684 @@
+use super::{
+    SeamParamFlatId, SeamParamKpId, SeamParamOcId, SeamParamOpId, SeamParamSfId, SeamParamValue,
+    SeamParamVpId, SeamParamXpId, SeamParamsV0,
+};
+use std::fmt;
+use std::sync::LazyLock;
+
+/// 一个代表接头识别参数分区的枚举。
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SeamParamArea {
+    Xp,
+    Kp,
+    Op,
+    Vp,
+    Oc,
+    Sf,
+}
+
+impl SeamParamArea {
+    /// 所有分区，按平面空间顺序排列。
+    pub const ALL: [SeamParamArea; 6] = [
+        SeamParamArea::Xp,
+        SeamParamArea::Kp,
+        SeamParamArea::Op,
+        SeamParamArea::Vp,
+        SeamParamArea::Oc,
+        SeamParamArea::Sf,
+    ];
+
+    /// 返回分区在平面空间中的起始编号。
+    pub fn offset(self) -> usize {
+        match self {
+            SeamParamArea::Xp => 0,
+            SeamParamArea::Kp => 30,
+            SeamParamArea::Op => 60,
+            SeamParamArea::Vp => 120,
+            SeamParamArea::Oc => 180,
+            SeamParamArea::Sf => 240,
+        }
+    }
+
+    /// 返回分区包含的参数个数。
+    pub fn count(self) -> usize {
+        match self {
+            SeamParamArea::Xp | SeamParamArea::Kp => 30,
+            SeamParamArea::Op | SeamParamArea::Vp | SeamParamArea::Oc => 60,
+            SeamParamArea::Sf => 10,
+        }
+    }
+
+    /// 返回分区的小写名称，如 `xp`。
+    pub fn name(self) -> &'static str {
+        match self {
+            SeamParamArea::Xp => "xp",
+            SeamParamArea::Kp => "kp",
+            SeamParamArea::Op => "op",
+            SeamParamArea::Vp => "vp",
+            SeamParamArea::Oc => "oc",
+            SeamParamArea::Sf => "sf",
+        }
+    }
+
+    /// 以小写名称查找分区。
+    pub fn from_name(name: &str) -> Option<Self> {
+        SeamParamArea::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    /// 返回分区内指定索引对应的平面空间编号。
+    pub fn flat_id(self, index: usize) -> SeamParamFlatId {
+        if index < self.count() {
+            SeamParamFlatId::from(self.offset() + index)
+        } else {
+            SeamParamFlatId::Invalid
+        }
+    }
+
+    /// 返回分区内所有参数的平面空间编号。
+    pub fn flat_ids(self) -> impl Iterator<Item = SeamParamFlatId> {
+        (0..self.count()).map(move |i| self.flat_id(i))
+    }
+}
+
+impl fmt::Display for SeamParamArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl SeamParamFlatId {
+    /// 返回参数所在的分区。
+    pub fn area(self) -> Option<SeamParamArea> {
+        let i = self as usize;
+        SeamParamArea::ALL
+            .into_iter()
+            .find(|a| i >= a.offset() && i < a.offset() + a.count())
+    }
+
+    /// 返回参数在所在分区中的索引。
+    pub fn area_index(self) -> Option<usize> {
+        self.area().map(|a| self as usize - a.offset())
+    }
+
+    /// 返回参数描述信息。
+    pub fn desc(self) -> Option<&'static SeamParamDesc> {
+        if self.is_valid() {
+            Some(&SEAM_PARAM_DESCS[self as usize])
+        } else {
+            None
+        }
+    }
+
+    /// 返回参数名称，如 `XpExposureTime`。
+    pub fn name(self) -> &'static str {
+        self.desc().map_or("Invalid", |d| d.name.as_str())
+    }
+
+    /// 以参数名称查找平面空间编号，支持 `XpExposureTime` 及 `xp.ExposureTime` 两种形式。
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Some((area, key)) = name.split_once('.') {
+            return SEAM_PARAM_DESCS
+                .iter()
+                .find(|d| d.area.name() == area && d.area_name == key)
+                .map(|d| d.id);
+        }
+        SEAM_PARAM_DESCS
+            .iter()
+            .find(|d| d.name == name)
+            .map(|d| d.id)
+    }
+}
+
+macro_rules! impl_area_id {
+    ($ty:ty, $area:expr) => {
+        impl From<$ty> for SeamParamFlatId {
+            fn from(value: $ty) -> Self {
+                if value.is_valid() {
+                    $area.flat_id(value as usize)
+                } else {
+                    SeamParamFlatId::Invalid
+                }
+            }
+        }
+
+        impl $ty {
+            /// 返回参数描述信息。
+            pub fn desc(self) -> Option<&'static SeamParamDesc> {
+                SeamParamFlatId::from(self).desc()
+            }
+        }
+    };
+}
+
+impl_area_id!(SeamParamXpId, SeamParamArea::Xp);
+impl_area_id!(SeamParamKpId, SeamParamArea::Kp);
+impl_area_id!(SeamParamOpId, SeamParamArea::Op);
+impl_area_id!(SeamParamVpId, SeamParamArea::Vp);
+impl_area_id!(SeamParamOcId, SeamParamArea::Oc);
+impl_area_id!(SeamParamSfId, SeamParamArea::Sf);
+
+/// 一个代表接头识别参数存储类型的枚举。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeamParamType {
+    /// 以 `i32_val` 存储。
+    I32,
+    /// 以 `f32_val` 存储。
+    F32,
+    /// 存储类型未经确认，按 `i32_val` 原样读写，不作换算及范围校验。
+    Raw,
+}
+
+/// 一个代表带类型的接头识别参数值的枚举。
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SeamParamTypedValue {
+    I32(i32),
+    F32(f32),
+}
+
+impl SeamParamTypedValue {
+    /// 返回值的类型。
+    pub fn ty(self) -> SeamParamType {
+        match self {
+            SeamParamTypedValue::I32(_) => SeamParamType::I32,
+            SeamParamTypedValue::F32(_) => SeamParamType::F32,
+        }
+    }
+
+    /// 返回以 `f64` 表示的数值。
+    pub fn as_f64(self) -> f64 {
+        match self {
+            SeamParamTypedValue::I32(v) => v as f64,
+            SeamParamTypedValue::F32(v) => v as f64,
+        }
+    }
+
+    /// 按指定类型转换数值。
+    pub fn cast(self, ty: SeamParamType) -> Self {
+        match (self, ty) {
+            (SeamParamTypedValue::F32(v), SeamParamType::I32) => {
+                SeamParamTypedValue::I32(v.round() as i32)
+            }
+            (SeamParamTypedValue::I32(v), SeamParamType::F32) => SeamParamTypedValue::F32(v as f32),
+            (v, _) => v,
+        }
+    }
+
+    /// 以指定类型解读原始参数值。
+    pub fn from_raw(raw: SeamParamValue, ty: SeamParamType) -> Self {
+        match ty {
+            SeamParamType::I32 => SeamParamTypedValue::I32(raw.into()),
+            SeamParamType::F32 => SeamParamTypedValue::F32(raw.into()),
+            SeamParamType::Raw => SeamParamTypedValue::I32(raw.into()),
+        }
+    }
+
+    /// 转换为原始参数值。
+    pub fn to_raw(self) -> SeamParamValue {
+        match self {
+            SeamParamTypedValue::I32(v) => v.into(),
+            SeamParamTypedValue::F32(v) => v.into(),
+        }
+    }
+}
+
+impl From<i32> for SeamParamTypedValue {
+    fn from(value: i32) -> Self {
+        SeamParamTypedValue::I32(value)
+    }
+}
+
+impl From<f32> for SeamParamTypedValue {
+    fn from(value: f32) -> Self {
+        SeamParamTypedValue::F32(value)
+    }
+}
+
+impl From<bool> for SeamParamTypedValue {
+    fn from(value: bool) -> Self {
+        SeamParamTypedValue::I32(value as i32)
+    }
+}
+
+impl fmt::Display for SeamParamTypedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeamParamTypedValue::I32(v) => write!(f, "{}", v),
+            SeamParamTypedValue::F32(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// 一个代表枚举型参数可选项的类型。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamParamChoice {
+    /// 选项值。
+    pub value: i32,
+    /// 选项名称。
+    pub name: &'static str,
+    /// 选项显示名称。
+    pub label: &'static str,
+}
+
+/// 一个代表接头识别参数描述信息的类型。
+#[derive(Clone, Debug)]
+pub struct SeamParamDesc {
+    /// 平面空间编号。
+    pub id: SeamParamFlatId,
+    /// 所在分区。
+    pub area: SeamParamArea,
+    /// 参数名称，如 `XpExposureTime`。
+    pub name: String,
+    /// 分区内参数名称，如 `ExposureTime`。
+    pub area_name: String,
+    /// 显示名称。
+    pub label: &'static str,
+    /// 存储类型。
+    pub ty: SeamParamType,
+    /// 计量单位。
+    pub unit: &'static str,
+    /// 最小值。
+    pub min: SeamParamTypedValue,
+    /// 最大值。
+    pub max: SeamParamTypedValue,
+    /// 默认值。
+    pub default: SeamParamTypedValue,
+    /// 枚举型参数的可选项。
+    pub choices: &'static [SeamParamChoice],
+    /// 是否为保留参数。
+    pub reserved: bool,
+}
+
+impl SeamParamDesc {
+    /// 检查数值是否在合法范围内。
+    pub fn contains(&self, value: SeamParamTypedValue) -> bool {
+        if self.ty == SeamParamType::Raw {
+            return true;
+        }
+        let value = value.cast(self.ty);
+        if let SeamParamTypedValue::F32(v) = value {
+            if !v.is_finite() {
+                return false;
+            }
+        }
+        if !self.choices.is_empty() {
+            let v: i32 = value.to_raw().into();
+            return self.choices.iter().any(|c| c.value == v);
+        }
+        let v = value.as_f64();
+        v >= self.min.as_f64() && v <= self.max.as_f64()
+    }
+
+    /// 以本参数类型解读原始参数值。
+    pub fn decode(&self, raw: SeamParamValue) -> SeamParamTypedValue {
+        SeamParamTypedValue::from_raw(raw, self.ty)
+    }
+
+    /// 按本参数类型编码为原始参数值。
+    pub fn encode(&self, value: SeamParamTypedValue) -> SeamParamValue {
+        value.cast(self.ty).to_raw()
+    }
+
+    /// 以名称查找枚举型参数的可选项。
+    pub fn choice_by_name(&self, name: &str) -> Option<&'static SeamParamChoice> {
+        self.choices.iter().find(|c| c.name == name)
+    }
+
+    /// 将参数值转换为 JSON 数值，浮点数以最短形式表示。
+    ///
+    /// 非有限的浮点数以 `"0x"` 开头的十六进制原始位串表示，保证可无损读回。
+    pub(super) fn to_json(&self, value: SeamParamTypedValue) -> serde_json::Value {
+        match value.cast(self.ty) {
+            SeamParamTypedValue::I32(v) => v.into(),
+            SeamParamTypedValue::F32(v) => v
+                .to_string()
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map_or_else(
+                    || format!("0x{:08x}", v.to_bits()).into(),
+                    serde_json::Value::Number,
+                ),
+        }
+    }
+
+    /// 从 JSON 数值、布尔值或可选项名称解析参数值，并转换为本参数类型。
+    ///
+    /// 未确认类型的参数只接受整数及 `"0x"` 开头的十六进制原始位串。
+    pub(super) fn parse_json(&self, value: &serde_json::Value) -> Option<SeamParamTypedValue> {
+        let value = match value {
+            serde_json::Value::Bool(v) => SeamParamTypedValue::from(*v),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(v) => SeamParamTypedValue::I32(i32::try_from(v).ok()?),
+                None if self.ty == SeamParamType::Raw => return None,
+                None => SeamParamTypedValue::F32(n.as_f64()? as f32),
+            },
+            serde_json::Value::String(name) => match self.choice_by_name(name) {
+                Some(choice) => choice.value.into(),
+                None => {
+                    let bits = u32::from_str_radix(name.strip_prefix("0x")?, 16).ok()?;
+                    match self.ty {
+                        SeamParamType::Raw => SeamParamTypedValue::I32(bits as i32),
+                        _ => SeamParamTypedValue::F32(f32::from_bits(bits)),
+                    }
+                }
+            },
+            _ => return None,
+        };
+        Some(value.cast(self.ty))
+    }
+}
+
+/// 返回所有接头识别参数的描述信息，按平面空间编号排列。
+pub fn seam_param_descs() -> &'static [SeamParamDesc] {
+    &SEAM_PARAM_DESCS
+}
+
+static SEAM_PARAM_DESCS: LazyLock<Vec<SeamParamDesc>> =
+    LazyLock::new(|| (0..250usize).map(build_desc).collect());
+
+/// 参数规格，不含编号及名称。
+struct Spec {
+    label: &'static str,
+    ty: SeamParamType,
+    unit: &'static str,
+    min: SeamParamTypedValue,
+    max: SeamParamTypedValue,
+    default: SeamParamTypedValue,
+    choices: &'static [SeamParamChoice],
+    reserved: bool,
+}
+
+impl Spec {
+    fn i32(label: &'static str, unit: &'static str, min: i32, max: i32, default: i32) -> Self {
+        Self {
+            label,
+            ty: SeamParamType::I32,
+            unit,
+            min: min.into(),
+            max: max.into(),
+            default: default.into(),
+            choices: &[],
+            reserved: false,
+        }
+    }
+
+    fn f32(label: &'static str, unit: &'static str, min: f32, max: f32, default: f32) -> Self {
+        Self {
+            label,
+            ty: SeamParamType::F32,
+            unit,
+            min: min.into(),
+            max: max.into(),
+            default: default.into(),
+            choices: &[],
+            reserved: false,
+        }
+    }
+
+    fn raw(label: &'static str) -> Self {
+        Self {
+            ty: SeamParamType::Raw,
+            ..Self::i32(label, "", i32::MIN, i32::MAX, 0)
+        }
+    }
+
+    fn bits(label: &'static str) -> Self {
+        Self::i32(label, "", 0, 0x3FFF_FFFF, 0)
+    }
+
+    fn reserved() -> Self {
+        Self {
+            reserved: true,
+            ..Self::i32("保留", "", i32::MIN, i32::MAX, 0)
+        }
+    }
+}
+
+fn build_desc(index: usize) -> SeamParamDesc {
+    use SeamParamFlatId::*;
+
+    // 参数编号及名称与 `fv/seam_profile.h` 的 `FV_SPA_F_*` 定义一一对应。
+    // 该头文件仅在用法示例中确认了提取算法为整数、KP 区角度 1、长度 1 及宽度 1
+    // 为浮点数，SF 区由 `fv_spa_v0_jtma()`、`fv_spa_v0_version()` 及各分区使能位
+    // 函数确认为整数；其余参数的类型、单位及取值范围均无算法文档可查，一律按
+    // 未确认类型处理，待算法组提供规格后再补充。
+    let id = SeamParamFlatId::from(index);
+    let spec = match id {
+        // XP 区
+        XpExposureControl => Spec::raw("曝光控制"),
+        XpExposureFramerate => Spec::raw("采集帧率"),
+        XpExposureTime => Spec::raw("曝光时间"),
+        XpLaserControl => Spec::raw("激光控制"),
+        XpLaserStrength => Spec::raw("激光强度"),
+        XpLampControl => Spec::raw("照明控制"),
+        XpLampPower => Spec::raw("照明功率"),
+        XpExtractionAlgo => Spec::i32("提取算法", "", i32::MIN, i32::MAX, 0),
+        XpLumaThresh => Spec::raw("亮度阈值"),
+        XpScanMode => Spec::raw("扫描模式"),
+        XpFilterTime => Spec::raw("滤波时间"),
+        XpRoiX => Spec::raw("感兴趣区域 X"),
+        XpRoiY => Spec::raw("感兴趣区域 Y"),
+        XpRoiW => Spec::raw("感兴趣区域宽度"),
+        XpRoiH => Spec::raw("感兴趣区域高度"),
+        // KP 区
+        KpAngle1 => Spec::f32("角度 1", "", f32::MIN, f32::MAX, 0.0),
+        KpAngle2 => Spec::raw("角度 2"),
+        KpLength1 => Spec::f32("长度 1", "", f32::MIN, f32::MAX, 0.0),
+        KpLength2 => Spec::raw("长度 2"),
+        KpWidth1 => Spec::f32("宽度 1", "", f32::MIN, f32::MAX, 0.0),
+        KpWidth2 => Spec::raw("宽度 2"),
+        KpThickness1 => Spec::raw("板厚 1"),
+        KpThickness2 => Spec::raw("板厚 2"),
+        KpGap1 => Spec::raw("间隙 1"),
+        KpGap2 => Spec::raw("间隙 2"),
+        KpDrop1 => Spec::raw("错边 1"),
+        KpDrop2 => Spec::raw("错边 2"),
+        // OP 区
+        OpDetectMethod => Spec::raw("检测方法"),
+        OpDirection => Spec::raw("检测方向"),
+        OpCornerType => Spec::raw("拐点类型"),
+        OpLayerSelect => Spec::raw("层道选择"),
+        OpGapPos => Spec::raw("间隙位置"),
+        OpGapType => Spec::raw("间隙类型"),
+        OpTrackPos => Spec::raw("跟踪位置"),
+        OpTrackType => Spec::raw("跟踪类型"),
+        OpBevelAngle1 => Spec::raw("坡口角度 1"),
+        OpBevelAngle2 => Spec::raw("坡口角度 2"),
+        OpNormalType1 => Spec::raw("法线类型 1"),
+        OpNormalType2 => Spec::raw("法线类型 2"),
+        // VP 区
+        VpAngleMin => Spec::raw("角度下限"),
+        VpAngleMax => Spec::raw("角度上限"),
+        VpGapMin => Spec::raw("间隙下限"),
+        VpGapMax => Spec::raw("间隙上限"),
+        VpDropMin => Spec::raw("错边下限"),
+        VpDropMax => Spec::raw("错边上限"),
+        VpGrooveAreaMin => Spec::raw("坡口面积下限"),
+        VpGrooveAreaMax => Spec::raw("坡口面积上限"),
+        VpGrooveAreaPos => Spec::raw("坡口面积位置"),
+        VpGrooveAreaType => Spec::raw("坡口面积类型"),
+        VpWidth1Min => Spec::raw("宽度 1 下限"),
+        VpWidth1Max => Spec::raw("宽度 1 上限"),
+        VpWidth2Min => Spec::raw("宽度 2 下限"),
+        VpWidth2Max => Spec::raw("宽度 2 上限"),
+        VpWidth3Min => Spec::raw("宽度 3 下限"),
+        VpWidth3Max => Spec::raw("宽度 3 上限"),
+        VpWidth4Min => Spec::raw("宽度 4 下限"),
+        VpWidth4Max => Spec::raw("宽度 4 上限"),
+        VpWeldedAngleMin => Spec::raw("焊后角度下限"),
+        VpWeldedAngleMax => Spec::raw("焊后角度上限"),
+        VpWeldedAreaMin => Spec::raw("焊后面积下限"),
+        VpWeldedAreaMax => Spec::raw("焊后面积上限"),
+        VpWeldedLengthMin => Spec::raw("焊后长度下限"),
+        VpWeldedLengthMax => Spec::raw("焊后长度上限"),
+        VpWeldedThicknessMin => Spec::raw("焊后厚度下限"),
+        VpWeldedThicknessMax => Spec::raw("焊后厚度上限"),
+        VpWeldedWidthMin => Spec::raw("焊后宽度下限"),
+        VpWeldedWidthMax => Spec::raw("焊后宽度上限"),
+        VpWeldedDetection => Spec::raw("焊后检测"),
+        VpNormalAngleMin => Spec::raw("法线角度下限"),
+        VpNormalAngleMax => Spec::raw("法线角度上限"),
+        VpL1A | VpL2A | VpL3A | VpL4A => Spec::raw("线段角度"),
+        VpL1APlus | VpL2APlus | VpL3APlus | VpL4APlus => Spec::raw("线段角度容差"),
+        VpL1Length | VpL2Length | VpL3Length | VpL4Length => Spec::raw("线段长度"),
+        // OC 区
+        OcFeaturePoint => Spec::raw("特征点"),
+        OcFilterStrength => Spec::raw("滤波强度"),
+        OcBasePosY => Spec::raw("基准位置 Y"),
+        OcBasePosZ => Spec::raw("基准位置 Z"),
+        OcBaseDeltaY => Spec::raw("基准偏差 Y"),
+        OcBaseDeltaZ => Spec::raw("基准偏差 Z"),
+        OcOffsetY => Spec::raw("输出偏移 Y"),
+        OcOffsetZ => Spec::raw("输出偏移 Z"),
+        OcStartPointFilter => Spec::raw("起点滤波"),
+        OcTrackingArea => Spec::raw("跟踪区域"),
+        OcTrackingStrength => Spec::raw("跟踪强度"),
+        OcTrackingDuration => Spec::raw("跟踪时长"),
+        // SF 区
+        SfJointType => Spec::i32("接头类型", "", 0, 0xffff, 0),
+        SfXpEn => Spec::bits("XP 参数使能"),
+        SfKpEn => Spec::bits("KP 参数使能"),
+        SfOpEn1 => Spec::bits("OP 参数使能 1"),
+        SfOpEn2 => Spec::bits("OP 参数使能 2"),
+        SfVpEn1 => Spec::bits("VP 参数使能 1"),
+        SfVpEn2 => Spec::bits("VP 参数使能 2"),
+        SfOcEn1 => Spec::bits("OC 参数使能 1"),
+        SfOcEn2 => Spec::bits("OC 参数使能 2"),
+        SfVersion => Spec::i32("参数版本", "", 0, 0xffff, 0),
+        _ => Spec::reserved(),
+    };
+    let area = id.area().unwrap_or(SeamParamArea::Sf);
+    let area_index = index - area.offset();
+    let area_name = match area {
+        SeamParamArea::Xp => format!("{:?}", SeamParamXpId::from(area_index)),
+        SeamParamArea::Kp => format!("{:?}", SeamParamKpId::from(area_index)),
+        SeamParamArea::Op => format!("{:?}", SeamParamOpId::from(area_index)),
+        SeamParamArea::Vp => format!("{:?}", SeamParamVpId::from(area_index)),
+        SeamParamArea::Oc => format!("{:?}", SeamParamOcId::from(area_index)),
+        SeamParamArea::Sf => format!("{:?}", SeamParamSfId::from(area_index)),
+    };
+    SeamParamDesc {
+        id,
+        area,
+        name: format!("{:?}", id),
+        area_name,
+        label: spec.label,
+        ty: spec.ty,
+        unit: spec.unit,
+        min: spec.min,
+        max: spec.max,
+        default: spec.default,
+        choices: spec.choices,
+        reserved: spec.reserved,
+    }
+}
+
+impl SeamParamsV0 {
+    /// 返回按参数描述类型解读的参数值，无效编号返回 `None`。
+    pub fn get<I: Into<SeamParamFlatId>>(&self, id: I) -> Option<SeamParamTypedValue> {
+        let desc = id.into().desc()?;
+        unsafe { Some(desc.decode(self.values[desc.id as usize])) }
+    }
+
+    /// 按参数描述类型写入参数值，数值类型不一致时自动转换。
+    pub fn set<I, V>(&mut self, id: I, value: V) -> bool
+    where
+        I: Into<SeamParamFlatId>,
+        V: Into<SeamParamTypedValue>,
+    {
+        match id.into().desc() {
+            Some(desc) => {
+                unsafe {
+                    self.values[desc.id as usize] = desc.encode(value.into());
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 返回所有参数均为默认值的参数表。
+    pub fn with_defaults() -> Self {
+        let mut v0 = Self::default();
+        for desc in seam_param_descs() {
+            v0.set(desc.id, desc.default);
+        }
+        v0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seam_param_desc() {
+        let descs = seam_param_descs();
+        assert_eq!(descs.len(), 250);
+        for (i, d) in descs.iter().enumerate() {
+            assert_eq!(d.id as usize, i);
+            assert!(d.contains(d.default), "{}", d.name);
+        }
+        let d = SeamParamXpId::ExposureTime.desc().unwrap();
+        assert_eq!(d.id, SeamParamFlatId::XpExposureTime);
+        assert_eq!(d.name, "XpExposureTime");
+        assert_eq!(d.area_name, "ExposureTime");
+        assert_eq!(d.ty, SeamParamType::Raw);
+        assert_eq!(
+            SeamParamFlatId::KpAngle1.desc().unwrap().ty,
+            SeamParamType::F32
+        );
+        assert_eq!(SeamParamFlatId::OpTrackPos as usize, 66);
+        assert_eq!(SeamParamFlatId::OpBevelAngle1 as usize, 68);
+        assert_eq!(SeamParamFlatId::Op57 as usize, 119);
+        assert_eq!(
+            SeamParamFlatId::from(SeamParamOpId::TrackType),
+            SeamParamFlatId::OpTrackType
+        );
+        assert!(SeamParamFlatId::Xp16.desc().unwrap().reserved);
+        assert_eq!(
+            SeamParamFlatId::from(SeamParamVpId::L1A),
+            SeamParamFlatId::VpL1A
+        );
+        assert_eq!(
+            SeamParamFlatId::from_name("vp.L4Length"),
+            Some(SeamParamFlatId::VpL4Length)
+        );
+        assert_eq!(
+            SeamParamFlatId::from_name("SfVersion"),
+            Some(SeamParamFlatId::SfVersion)
+        );
+        assert!(SeamParamFlatId::Invalid.desc().is_none());
+    }
+
+    #[test]
+    fn test_seam_params_v0_typed() {
+        let mut v0 = SeamParamsV0::default();
+        assert!(v0.set(SeamParamKpId::Angle1, 60));
+        assert_eq!(v0.kp_f32(SeamParamKpId::Angle1), 60.0);
+        assert!(v0.set(SeamParamFlatId::XpExtractionAlgo, 2.6f32));
+        assert_eq!(v0.xp_i32(SeamParamXpId::ExtractionAlgo), 3);
+        assert_eq!(
+            v0.get(SeamParamFlatId::KpAngle1),
+            Some(SeamParamTypedValue::F32(60.0))
+        );
+
+        // 未确认类型的参数按原始位串读写
+        assert!(v0.set(SeamParamFlatId::OpBevelAngle1, 30.0f32));
+        assert_eq!(
+            v0.get(SeamParamFlatId::OpBevelAngle1),
+            Some(SeamParamTypedValue::I32(30.0f32.to_bits() as i32))
+        );
+        assert!(v0.set(SeamParamFlatId::OpTrackPos, 2));
+        assert_eq!(v0.op_i32(SeamParamOpId::TrackPos), 2);
+        let desc = SeamParamFlatId::OpTrackPos.desc().unwrap();
+        assert!(desc.contains(SeamParamTypedValue::I32(-1)));
+        assert_eq!(desc.parse_json(&serde_json::json!(1.5)), None);
+        assert_eq!(
+            desc.parse_json(&serde_json::json!("0x3f800000")),
+            Some(SeamParamTypedValue::I32(0x3f80_0000))
+        );
+        assert!(!v0.set(SeamParamFlatId::Invalid, 1));
+        assert_eq!(v0.get(SeamParamXpId::Invalid), None);
+    }
+}
@@ -0,0 +1,430 @@
+use super::{
+    SeamParamFlatId, SeamParamViolation, SeamProfile, SeamProfileDiff, SeamProfileError,
+    SeamProfileManager,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// 一个代表按参数名称修改配置的补丁类型。
+///
+/// 例如 `{"id":3,"set":{"XpExposureTime":1200}}`，参数名称支持 `XpExposureTime`、
+/// `xp.ExposureTime` 及平面空间编号，参数值支持数值、布尔值及可选项名称。
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeamProfilePatch {
+    /// 配置编号。
+    pub id: i32,
+    /// 新的使能状态。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// 新的配置名称。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 需要修改的参数。
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub set: Map<String, Value>,
+}
+
+/// 一个代表 RFC 6902 JSON Patch 操作的枚举。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum SeamJsonPatchOp {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// 补丁请求的 JSON 形式，`patch` 存在时为 JSON Patch，含有未知字段时解析失败。
+#[derive(Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+enum SeamPatchRequest {
+    JsonPatch {
+        id: i32,
+        patch: Vec<SeamJsonPatchOp>,
+    },
+    Fields(SeamProfilePatch),
+}
+
+/// 以名称或平面空间编号查找参数。
+fn param_id(key: &str) -> Result<SeamParamFlatId, SeamProfileError> {
+    SeamParamFlatId::from_name(key)
+        .or_else(|| key.parse::<usize>().ok().map(SeamParamFlatId::from))
+        .filter(|id| id.is_valid())
+        .ok_or_else(|| SeamProfileError::UnknownParam(key.into()))
+}
+
+/// 按参数描述修改参数，不在合法范围内的值记入违规列表。
+fn set_param(
+    profile: &mut SeamProfile,
+    key: &str,
+    value: &Value,
+    violations: &mut Vec<SeamParamViolation>,
+) -> Result<(), SeamProfileError> {
+    let id = param_id(key)?;
+    let desc = id
+        .desc()
+        .ok_or_else(|| SeamProfileError::UnknownParam(key.into()))?;
+    let typed = desc
+        .parse_json(value)
+        .ok_or_else(|| SeamProfileError::Patch(format!("参数 {} 的值 {} 无效", key, value)))?;
+    if !desc.contains(typed) {
+        violations.push(SeamParamViolation::OutOfRange { id, value: typed });
+    }
+    profile.v0.set(id, typed);
+    Ok(())
+}
+
+/// 返回配置的补丁视图，JSON Patch 操作作用于该视图。
+///
+/// 视图形如 `{"enabled":true,"name":"...","params":{"XpExposureTime":1000,...}}`，
+/// 保留参数不在视图中。
+fn patch_view(profile: &SeamProfile) -> Value {
+    let params: Map<String, Value> = super::seam_param_descs()
+        .iter()
+        .filter(|d| !d.reserved)
+        .filter_map(|d| Some((d.name.clone(), d.to_json(profile.v0.get(d.id)?))))
+        .collect();
+    serde_json::json!({
+        "enabled": profile.is_enabled(),
+        "name": profile.name(),
+        "params": params,
+    })
+}
+
+/// 解析 JSON 指针。
+fn parse_pointer(path: &str) -> Result<Vec<String>, SeamProfileError> {
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(path) = path.strip_prefix('/') else {
+        return Err(SeamProfileError::Patch(format!("无效的路径 {}", path)));
+    };
+    Ok(path
+        .split('/')
+        .map(|t| t.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+/// 返回 JSON 指针所指位置的父节点及末级键名。
+fn parent_mut<'a>(
+    doc: &'a mut Value,
+    path: &str,
+) -> Result<(&'a mut Value, String), SeamProfileError> {
+    let mut tokens = parse_pointer(path)?;
+    let last = tokens
+        .pop()
+        .ok_or_else(|| SeamProfileError::Patch("不能修改根节点".into()))?;
+    let mut parent = String::new();
+    for t in &tokens {
+        parent.push('/');
+        parent.push_str(&t.replace('~', "~0").replace('/', "~1"));
+    }
+    let node = doc
+        .pointer_mut(&parent)
+        .ok_or_else(|| SeamProfileError::Patch(format!("路径 {} 不存在", path)))?;
+    Ok((node, last))
+}
+
+/// 解析数组下标，`allow_end` 为 `true` 时允许指向末尾。
+fn array_index(token: &str, len: usize, allow_end: bool) -> Result<usize, SeamProfileError> {
+    let index = if token == "-" && allow_end {
+        len
+    } else {
+        token
+            .parse::<usize>()
+            .map_err(|_| SeamProfileError::Patch(format!("无效的数组下标 {}", token)))?
+    };
+    if index < len || (allow_end && index == len) {
+        Ok(index)
+    } else {
+        Err(SeamProfileError::Patch(format!("数组下标 {} 越界", token)))
+    }
+}
+
+fn json_add(doc: &mut Value, path: &str, value: Value) -> Result<(), SeamProfileError> {
+    let (parent, key) = parent_mut(doc, path)?;
+    match parent {
+        Value::Object(map) => {
+            map.insert(key, value);
+        }
+        Value::Array(arr) => {
+            let index = array_index(&key, arr.len(), true)?;
+            arr.insert(index, value);
+        }
+        _ => return Err(SeamProfileError::Patch(format!("路径 {} 不存在", path))),
+    }
+    Ok(())
+}
+
+fn json_remove(doc: &mut Value, path: &str) -> Result<Value, SeamProfileError> {
+    let (parent, key) = parent_mut(doc, path)?;
+    let removed = match parent {
+        Value::Object(map) => map.remove(&key),
+        Value::Array(arr) => {
+            let index = array_index(&key, arr.len(), false)?;
+            Some(arr.remove(index))
+        }
+        _ => None,
+    };
+    removed.ok_or_else(|| SeamProfileError::Patch(format!("路径 {} 不存在", path)))
+}
+
+fn json_get<'a>(doc: &'a Value, path: &str) -> Result<&'a Value, SeamProfileError> {
+    doc.pointer(path)
+        .ok_or_else(|| SeamProfileError::Patch(format!("路径 {} 不存在", path)))
+}
+
+impl SeamJsonPatchOp {
+    /// 对 JSON 文档执行本操作。
+    fn apply(&self, doc: &mut Value) -> Result<(), SeamProfileError> {
+        match self {
+            SeamJsonPatchOp::Add { path, value } => json_add(doc, path, value.clone()),
+            SeamJsonPatchOp::Remove { path } => json_remove(doc, path).map(|_| ()),
+            SeamJsonPatchOp::Replace { path, value } => {
+                let target = doc
+                    .pointer_mut(path)
+                    .ok_or_else(|| SeamProfileError::Patch(format!("路径 {} 不存在", path)))?;
+                *target = value.clone();
+                Ok(())
+            }
+            SeamJsonPatchOp::Move { from, path } => {
+                if path.starts_with(&format!("{}/", from)) {
+                    return Err(SeamProfileError::Patch(format!(
+                        "不能将 {} 移动到其子节点 {}",
+                        from, path
+                    )));
+                }
+                let value = json_remove(doc, from)?;
+                json_add(doc, path, value)
+            }
+            SeamJsonPatchOp::Copy { from, path } => {
+                let value = json_get(doc, from)?.clone();
+                json_add(doc, path, value)
+            }
+            SeamJsonPatchOp::Test { path, value } => {
+                if json_get(doc, path)? == value {
+                    Ok(())
+                } else {
+                    Err(SeamProfileError::Patch(format!(
+                        "路径 {} 的值与 {} 不符",
+                        path, value
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl SeamProfileManager {
+    /// 按参数名称修改配置，返回变更内容。
+    ///
+    /// 所有修改均通过校验后才一次性生效并提交一次变更记录，
+    /// 任一参数无效或超出范围时不做任何修改。
+    pub fn apply_patch(
+        &mut self,
+        patch: &SeamProfilePatch,
+    ) -> Result<SeamProfileDiff, SeamProfileError> {
+        let mut profile = self.patch_base(patch.id)?;
+        if let Some(enabled) = patch.enabled {
+            profile.enabled = enabled;
+        }
+        if let Some(name) = &patch.name {
+            profile.meta.set_name(name.as_str());
+        }
+        let mut violations = Vec::new();
+        for (key, value) in &patch.set {
+            set_param(&mut profile, key, value, &mut violations)?;
+        }
+        self.commit_patch(profile, violations)
+    }
+
+    /// 对配置的补丁视图执行 RFC 6902 JSON Patch，返回变更内容。
+    ///
+    /// 补丁视图形如 `{"enabled":true,"name":"...","params":{"XpExposureTime":1000}}`，
+    /// 删除参数表示恢复为默认值。所有操作均成功且通过校验后才一次性生效。
+    pub fn apply_json_patch(
+        &mut self,
+        id: usize,
+        ops: &[SeamJsonPatchOp],
+    ) -> Result<SeamProfileDiff, SeamProfileError> {
+        let mut profile = self.patch_base(id as i32)?;
+        let orig = patch_view(&profile);
+        let mut doc = orig.clone();
+        for op in ops {
+            op.apply(&mut doc)?;
+        }
+
+        let Value::Object(mut doc) = doc else {
+            return Err(SeamProfileError::Patch("补丁结果不是对象".into()));
+        };
+        match doc.remove("enabled") {
+            Some(Value::Bool(enabled)) => profile.enabled = enabled,
+            Some(value) => {
+                return Err(SeamProfileError::Patch(format!("无效的使能状态 {}", value)))
+            }
+            None => profile.enabled = false,
+        }
+        match doc.remove("name") {
+            Some(Value::String(name)) => profile.meta.set_name(name),
+            Some(value) => {
+                return Err(SeamProfileError::Patch(format!("无效的配置名称 {}", value)))
+            }
+            None => profile.meta.set_name(""),
+        }
+        let params = match doc.remove("params") {
+            Some(Value::Object(params)) => params,
+            _ => return Err(SeamProfileError::Patch("缺少参数表 params".into())),
+        };
+        if let Some(key) = doc.keys().next() {
+            return Err(SeamProfileError::Patch(format!("未知的字段 {}", key)));
+        }
+
+        let mut violations = Vec::new();
+        for desc in super::seam_param_descs().iter().filter(|d| !d.reserved) {
+            if !params.contains_key(&desc.name) {
+                profile.v0.set(desc.id, desc.default);
+            }
+        }
+        for (key, value) in &params {
+            if orig["params"].get(key) != Some(value) {
+                set_param(&mut profile, key, value, &mut violations)?;
+            }
+        }
+        self.commit_patch(profile, violations)
+    }
+
+    /// 从 JSON 字符串加载补丁并修改配置，返回变更内容。
+    ///
+    /// 支持 [`SeamProfilePatch`] 及 `{"id":3,"patch":[...]}` 形式的 JSON Patch。
+    pub fn apply_patch_json_str(
+        &mut self,
+        json: &str,
+    ) -> Result<SeamProfileDiff, SeamProfileError> {
+        match serde_json::from_str::<SeamPatchRequest>(json)? {
+            SeamPatchRequest::JsonPatch { id, patch } => {
+                self.try_get_profile(id as usize)?;
+                self.apply_json_patch(id as usize, &patch)
+            }
+            SeamPatchRequest::Fields(patch) => self.apply_patch(&patch),
+        }
+    }
+
+    /// 返回指定编号配置的副本，用于应用补丁。
+    pub(super) fn patch_base(&self, id: i32) -> Result<SeamProfile, SeamProfileError> {
+        if id < 0 {
+            return Err(SeamProfileError::InvalidId(id));
+        }
+        let current = self.try_get_profile(id as usize)?;
+        let mut profile = SeamProfile::new(current.is_enabled(), id);
+        profile.merge_with_commit(current);
+        Ok(profile)
+    }
+
+    /// 校验并提交已应用补丁的配置。
+    fn commit_patch(
+        &mut self,
+        mut profile: SeamProfile,
+        mut violations: Vec<SeamParamViolation>,
+    ) -> Result<SeamProfileDiff, SeamProfileError> {
+        profile.sync_meta();
+        for v in profile.validate_with(&self.validate_options) {
+            if !violations.contains(&v) {
+                violations.push(v);
+            }
+        }
+        if !violations.is_empty() {
+            return Err(SeamProfileError::Validation {
+                id: profile.id(),
+                violations,
+            });
+        }
+        Ok(self.update_profile(&profile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::SeamParamTypedValue;
+
+    fn manager(name: &str) -> (SeamProfileManager, TestDir) {
+        let dir = TestDir::new(name);
+        let mgr = SeamProfileManager::new(
+            format!("{}/backup", dir.to_str()),
+            format!("{}/config", dir.to_str()),
+        );
+        (mgr, dir)
+    }
+
+    #[test]
+    fn test_apply_patch() {
+        let (mut mgr, _dir) = manager("patch");
+        let diff = mgr
+            .apply_patch_json_str(r#"{"id":3,"name":"Patched","set":{"XpExposureTime":1200}}"#)
+            .unwrap();
+        assert_eq!(diff.params.len(), 1);
+        assert_eq!(mgr.get_profile(3).name(), "Patched");
+        assert_eq!(
+            mgr.get_profile(3).v0.get(SeamParamFlatId::XpExposureTime),
+            Some(SeamParamTypedValue::I32(1200))
+        );
+        assert_eq!(mgr.commits(), 1);
+
+        let r = mgr
+            .apply_patch_json_str(r#"{"id":3,"set":{"XpExposureTime":1300,"XpExposureTime2":1}}"#);
+        assert!(matches!(r, Err(SeamProfileError::UnknownParam(_))));
+        let r = mgr.apply_patch_json_str(r#"{"id":3,"set":{"KpAngle1":"0x7fc00000"}}"#);
+        assert!(matches!(r, Err(SeamProfileError::Validation { .. })));
+        let r = mgr.apply_patch_json_str(r#"{"id":3,"set":{"XpExposureTime":1.5}}"#);
+        assert!(r.is_err());
+        assert_eq!(
+            mgr.get_profile(3).v0.get(SeamParamFlatId::XpExposureTime),
+            Some(SeamParamTypedValue::I32(1200))
+        );
+        assert_eq!(mgr.commits(), 1);
+
+        for json in [
+            r#"{"id":3,"sett":{"XpExposureTime":1300}}"#,
+            r#"{"id":3,"set":{"XpExposureTime":1300},"patch":[]}"#,
+        ] {
+            let r = mgr.apply_patch_json_str(json);
+            assert!(matches!(r, Err(SeamProfileError::Json(_))));
+        }
+        assert_eq!(mgr.commits(), 1);
+    }
+
+    #[test]
+    fn test_apply_json_patch() {
+        let (mut mgr, _dir) = manager("json-patch");
+        let json = r#"{"id":5,"patch":[
+            {"op":"test","path":"/enabled","value":false},
+            {"op":"replace","path":"/enabled","value":true},
+            {"op":"replace","path":"/params/XpExposureFramerate","value":60},
+            {"op":"replace","path":"/params/XpExposureTime","value":1200},
+            {"op":"copy","from":"/params/XpExposureTime","path":"/params/XpFilterTime"}
+        ]}"#;
+        let diff = mgr.apply_patch_json_str(json).unwrap();
+        assert_eq!(diff.enabled, Some((false, true)));
+        assert_eq!(
+            mgr.get_profile(5).v0.get(SeamParamFlatId::XpFilterTime),
+            Some(SeamParamTypedValue::I32(1200))
+        );
+
+        let json = r#"{"id":5,"patch":[
+            {"op":"replace","path":"/params/XpFilterTime","value":0},
+            {"op":"test","path":"/enabled","value":false}
+        ]}"#;
+        assert!(matches!(
+            mgr.apply_patch_json_str(json),
+            Err(SeamProfileError::Patch(_))
+        ));
+        assert_eq!(
+            mgr.get_profile(5).v0.get(SeamParamFlatId::XpFilterTime),
+            Some(SeamParamTypedValue::I32(1200))
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use super::{
+    SeamParamFlatId, SeamParamsV0, SeamParamsV1, SeamProfileManager, SeamProfileManagerHandle,
+};
+use arc_swap::{ArcSwap, Guard};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// 一个代表已发布的当前生效参数表快照的类型。
+#[derive(Clone, Debug, Default)]
+pub struct SeamParamsSnapshot {
+    /// 配置编号。
+    pub profile_id: usize,
+    /// 发布代号，每次发布后加 1。
+    pub generation: u64,
+    /// 参数表。
+    pub params: SeamParamsV1,
+}
+
+impl SeamParamsSnapshot {
+    /// 返回 V0 版参数表。
+    pub fn v0(&self) -> &SeamParamsV0 {
+        &self.params.v0
+    }
+
+    /// 返回指定参数的 32 位浮点值。
+    pub fn value_f32(&self, index: SeamParamFlatId) -> f32 {
+        self.params.v0.value_f32(index)
+    }
+
+    /// 返回指定参数的 32 位整型值。
+    pub fn value_i32(&self, index: SeamParamFlatId) -> i32 {
+        self.params.v0.value_i32(index)
+    }
+}
+
+/// 一个发布当前生效参数表快照的类型。
+///
+/// 写入方为配置管理器，切换配置或当前配置的参数表有变化时替换快照；读取方从不阻塞，
+/// 可先比较发布代号判断是否有变化，适用于实时跟踪循环。
+pub struct SeamParamsPublisher {
+    generation: AtomicU64,
+    current: ArcSwap<SeamParamsSnapshot>,
+}
+
+impl SeamParamsPublisher {
+    /// 创建一个新的发布器，发布的参数表全部为零。
+    pub fn new() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            current: ArcSwap::from_pointee(SeamParamsSnapshot::default()),
+        }
+    }
+
+    /// 返回最近一次发布的代号，与 [`SeamParamsPublisher::load`] 返回的快照一致。
+    pub fn generation(&self) -> u64 {
+        self.current.load().generation
+    }
+
+    /// 发布指定配置的参数表，同一时刻只能有一个写入方。
+    pub fn publish(&self, profile_id: usize, params: &SeamParamsV1) {
+        let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+        self.current.store(Arc::new(SeamParamsSnapshot {
+            profile_id,
+            generation,
+            params: *params,
+        }));
+    }
+
+    /// 返回最近发布的快照，适用于频繁读取的场合。
+    pub fn load(&self) -> Guard<Arc<SeamParamsSnapshot>> {
+        self.current.load()
+    }
+
+    /// 返回最近发布的快照的共享引用。
+    pub fn snapshot(&self) -> Arc<SeamParamsSnapshot> {
+        self.current.load_full()
+    }
+
+    /// 读取最近发布的配置编号及 V0 版参数表。
+    pub fn read_v0(&self) -> (usize, SeamParamsV0) {
+        let snapshot = self.load();
+        (snapshot.profile_id, snapshot.params.v0)
+    }
+
+    /// 将最近发布的参数表复制到 `dst` 中，返回配置编号。
+    pub fn read_into(&self, dst: &mut SeamParamsV1) -> usize {
+        let snapshot = self.load();
+        *dst = snapshot.params;
+        snapshot.profile_id
+    }
+}
+
+impl Default for SeamParamsPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SeamProfileManager {
+    /// 返回当前生效参数表的发布器，可在不持有管理器锁的情况下读取。
+    pub fn publisher(&self) -> &Arc<SeamParamsPublisher> {
+        &self.publisher
+    }
+
+    /// 返回当前生效参数表的快照。
+    pub fn snapshot(&self) -> Arc<SeamParamsSnapshot> {
+        self.publisher.snapshot()
+    }
+
+    /// 返回全局配置管理器的发布器，仅首次调用时获取管理器锁。
+    pub fn global_publisher() -> &'static Arc<SeamParamsPublisher> {
+        SeamProfileManagerHandle::global().publisher()
+    }
+
+    /// 发布当前生效的参数表。
+    pub(super) fn publish_current(&self) {
+        self.publisher
+            .publish(self.current_index, self.current_profile().v1());
+    }
+
+    /// 当前生效的参数表与已发布的不一致时重新发布，返回是否发布。
+    ///
+    /// 通过 [`SeamProfile`](super::SeamProfile) 的方法或 C 接口返回的指针直接修改当前配置后，
+    /// 需调用此方法使无锁读取方看到新的参数表；C 接口每次锁定管理器时自动调用。
+    pub fn republish(&self) -> bool {
+        let snapshot = self.publisher.load();
+        let published: &[i32] = snapshot.params.as_ref();
+        let current: &[i32] = self.current_profile().v1().as_ref();
+        if snapshot.profile_id == self.current_index && published == current {
+            return false;
+        }
+        drop(snapshot);
+        self.publish_current();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_publisher() {
+        let dir = TestDir::new("publish");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        let publisher = mgr.publisher().clone();
+        let generation = publisher.generation();
+        mgr.set_cur_v0(SeamParamFlatId::XpExposureTime, 800.0f32);
+        assert!(publisher.generation() > generation);
+        let snapshot = mgr.snapshot();
+        assert_eq!(snapshot.generation, publisher.generation());
+        assert_eq!(snapshot.profile_id, 0);
+        assert_eq!(snapshot.value_f32(SeamParamFlatId::XpExposureTime), 800.0);
+        mgr.get_profile_mut(3)
+            .set_v0_value_f32(SeamParamFlatId::XpExposureTime, 700.0);
+        mgr.set_current_profile_name("Same");
+        mgr.commit();
+        assert_eq!(publisher.generation(), snapshot.generation);
+        mgr.set_current_profile_id(5);
+        assert_eq!(publisher.load().profile_id, 5);
+        assert_eq!(snapshot.profile_id, 0);
+
+        let reader = {
+            let publisher = Arc::clone(&publisher);
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    let snapshot = publisher.load();
+                    let raw: &[i32] = snapshot.v0().as_ref();
+                    assert!(raw[..30].iter().all(|&v| v == raw[0]));
+                    assert!(snapshot.generation <= publisher.generation());
+                }
+            })
+        };
+        for i in 0..1000 {
+            let mut v1 = SeamParamsV1::default();
+            let raw: &mut [i32] = v1.v0.as_mut();
+            raw[..30].fill(i);
+            publisher.publish(5, &v1);
+        }
+        reader.join().unwrap();
+    }
+
+    #[test]
+    fn test_publish_after_load() {
+        let dir = TestDir::new("publish-load");
+        let dir = dir.to_str();
+        let backup_dir = format!("{}/backup", dir);
+        let config_dir = format!("{}/config", dir);
+        let mut mgr = SeamProfileManager::try_new(&backup_dir, &config_dir).unwrap();
+        mgr.load_all_profiles().unwrap();
+        mgr.set_cur_v0(SeamParamFlatId::XpExposureTime, 900.0f32);
+        mgr.flush().unwrap();
+
+        let mut mgr = SeamProfileManager::try_new(&backup_dir, &config_dir).unwrap();
+        mgr.load_all_profiles().unwrap();
+        let snapshot = mgr.snapshot();
+        assert_eq!(snapshot.value_f32(SeamParamFlatId::XpExposureTime), 900.0);
+        assert!(!mgr.republish());
+
+        mgr.get_profile_mut(0)
+            .set_v0_value_f32(SeamParamFlatId::XpExposureTime, 700.0);
+        assert!(mgr.republish());
+        assert!(mgr.snapshot().generation > snapshot.generation);
+        assert_eq!(
+            mgr.snapshot().value_f32(SeamParamFlatId::XpExposureTime),
+            700.0
+        );
+    }
+}
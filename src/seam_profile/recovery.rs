@@ -0,0 +1,233 @@
+use super::{write_atomic, SeamProfile, SeamProfileError, SeamProfileEvent, SeamProfileManager};
+use log::{debug, error, info, warn};
+use std::io;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// 一个代表配置加载结果报告的类型。
+#[derive(Debug, Default)]
+pub struct SeamProfileLoadReport {
+    /// 正常加载的配置编号。
+    pub loaded: Vec<usize>,
+    /// 文件缺失，以默认值新建的配置编号。
+    pub created: Vec<usize>,
+    /// 文件损坏，从备份中恢复的配置编号及所用备份文件。
+    pub recovered: Vec<(usize, PathBuf)>,
+    /// 文件损坏且无可用备份，重置为默认值的配置编号。
+    pub reset: Vec<usize>,
+    /// 未能加载并保留原文件的配置编号及原因。
+    pub skipped: Vec<(usize, SeamProfileError)>,
+}
+
+impl SeamProfileLoadReport {
+    /// 是否所有配置均正常加载或新建。
+    pub fn is_clean(&self) -> bool {
+        self.recovered.is_empty() && self.reset.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// 一个代表加载时需要重建的配置文件的类型。
+pub(super) struct SeamProfileRebuild {
+    pub(super) id: usize,
+    pub(super) path: String,
+    pub(super) text: String,
+    /// 损坏文件的另存路径，重建前先将原文件重命名到此路径。
+    pub(super) corrupt: Option<String>,
+}
+
+impl SeamProfileRebuild {
+    /// 以同步方式重建配置文件。
+    fn write(&self) -> Result<(), SeamProfileError> {
+        if let Some(corrupt) = &self.corrupt {
+            std::fs::rename(&self.path, corrupt)?;
+        }
+        write_atomic(&self.path, &self.text)?;
+        debug!("配置 #{} 已经保存到 {}", self.id, self.path);
+        Ok(())
+    }
+}
+
+/// 返回备份目录中所有备份文件，按修改时间从新到旧排列。
+pub(super) fn backup_tarballs(backup_dir: &str) -> Vec<PathBuf> {
+    let mut tarballs: Vec<(SystemTime, PathBuf)> = std::fs::read_dir(backup_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("profiles-") && n.ends_with(".tar.gz"))
+        })
+        .filter_map(|p| {
+            let mtime = p.metadata().and_then(|m| m.modified()).ok()?;
+            Some((mtime, p))
+        })
+        .collect();
+    tarballs.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    tarballs.into_iter().map(|(_, p)| p).collect()
+}
+
+impl SeamProfileManager {
+    /// 从文件加载所有配置。
+    ///
+    /// 缺失的配置以默认值创建；损坏的配置从最新的可用备份中恢复，
+    /// 无可用备份时将损坏文件另存为 `*.corrupt` 后重置为默认值。
+    /// 仅在写入文件失败时返回错误。
+    pub fn load_all_profiles(&mut self) -> Result<SeamProfileLoadReport, SeamProfileError> {
+        let (report, rebuilds) = self.load_all_profiles_with(|mgr, i| mgr.load_profile(i))?;
+        for rebuild in &rebuilds {
+            rebuild.write()?;
+        }
+        Ok(report)
+    }
+
+    /// 以指定函数读取每个配置，其余与 [`SeamProfileManager::load_all_profiles`] 相同。
+    ///
+    /// 缺失或损坏的配置文件不在此处写入，而是作为重建任务返回，由调用方在适当时机写入。
+    pub(super) fn load_all_profiles_with<F>(
+        &mut self,
+        mut load: F,
+    ) -> Result<(SeamProfileLoadReport, Vec<SeamProfileRebuild>), SeamProfileError>
+    where
+        F: FnMut(&mut Self, usize) -> Result<SeamProfile, SeamProfileError>,
+    {
+        let mut report = SeamProfileLoadReport::default();
+        let mut rebuilds = Vec::new();
+        for i in 0..self.profiles.len() {
+            match load(self, i) {
+                Ok(profile) => {
+                    self.get_profile_mut(i).merge_with_commit(&profile);
+                    self.profiles_ffi[i].enabled = profile.enabled as i32;
+                    self.existing[i] = true;
+                    report.loaded.push(i);
+                }
+                Err(SeamProfileError::Io(err))
+                    if err.kind() == io::ErrorKind::NotFound && self.sparse =>
+                {
+                    let profile = SeamProfile::new(false, i as i32);
+                    self.get_profile_mut(i).merge_with_commit(&profile);
+                    self.profiles_ffi[i].enabled = 0;
+                    self.existing[i] = false;
+                }
+                Err(SeamProfileError::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+                    warn!("配置 #{} 不存在，创建默认配置 ...", i);
+                    rebuilds.push(self.rebuild(i, None)?);
+                    report.created.push(i);
+                }
+                Err(SeamProfileError::Json(err)) => {
+                    error!("配置 #{} 已损坏：{}", i, err);
+                    if let Some((profile, tarball)) = self.recover_profile(i) {
+                        info!("配置 #{} 已从备份 {} 恢复", i, tarball.display());
+                        self.get_profile_mut(i).merge_with_commit(&profile);
+                        self.profiles_ffi[i].enabled = profile.enabled as i32;
+                        rebuilds.push(self.rebuild(i, None)?);
+                        report.recovered.push((i, tarball));
+                    } else {
+                        let path = self.profile_path(i);
+                        let corrupt = format!("{}.corrupt", path);
+                        warn!("配置 #{} 无可用备份，另存为 {} 并重置", i, corrupt);
+                        rebuilds.push(self.rebuild(i, Some(corrupt))?);
+                        report.reset.push(i);
+                    }
+                }
+                Err(err) => {
+                    error!("配置 #{} 加载失败，保留文件并跳过加载：{}", i, err);
+                    report.skipped.push((i, err));
+                }
+            }
+        }
+        let mut ids = report.loaded.clone();
+        ids.extend(report.recovered.iter().map(|(i, _)| *i));
+        ids.sort_unstable();
+        if let Some(id) = self.ensure_current_exists()? {
+            rebuilds.push(self.rebuild(id, None)?);
+            self.profiles[id].flush();
+            report.created.push(id);
+        }
+        self.republish();
+        if !ids.is_empty() {
+            self.emit(SeamProfileEvent::ProfilesReloaded { ids });
+        }
+        Ok((report, rebuilds))
+    }
+
+    /// 生成指定编号配置的重建任务，并记录为最近一次保存的内容。
+    fn rebuild(
+        &self,
+        id: usize,
+        corrupt: Option<String>,
+    ) -> Result<SeamProfileRebuild, SeamProfileError> {
+        let text = self.profile_text(id)?;
+        self.mark_saved(id, &text);
+        Ok(SeamProfileRebuild {
+            id,
+            path: self.profile_path(id),
+            text,
+            corrupt,
+        })
+    }
+
+    /// 返回指定编号配置的文件路径。
+    pub fn profile_path(&self, id: usize) -> String {
+        format!("{}/seam-profile-{}.json", self.config_dir, id)
+    }
+
+    /// 返回备份目录中所有备份文件，按修改时间从新到旧排列。
+    pub fn backup_tarballs(&self) -> Vec<PathBuf> {
+        backup_tarballs(&self.backup_dir)
+    }
+
+    /// 从最新的可用备份中读取指定编号的配置。
+    fn recover_profile(&self, id: usize) -> Option<(SeamProfile, PathBuf)> {
+        for tarball in self.backup_tarballs() {
+            match self.read_archive_profile(&tarball, id) {
+                Ok(profile) => return Some((profile, tarball)),
+                Err(err) => warn!(
+                    "备份 {} 中的配置 #{} 不可用：{}",
+                    tarball.display(),
+                    id,
+                    err
+                ),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_recover_corrupt_profile() {
+        let dir = TestDir::new("recovery");
+        let dir = dir.to_str();
+        let mut mgr =
+            SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir))
+                .unwrap();
+        let report = mgr.load_all_profiles().unwrap();
+        assert_eq!(report.created.len(), 256);
+
+        mgr.get_profile_mut(5).set_name("Backup");
+        mgr.save_profile(5).unwrap();
+        let tarball = PathBuf::from(format!("{}/backup/profiles-0.tar.gz", dir));
+        mgr.tar_all(tarball.to_str().unwrap()).unwrap();
+        std::fs::write(mgr.profile_path(5), "{\"schema\":").unwrap();
+
+        let mut mgr =
+            SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir))
+                .unwrap();
+        let report = mgr.load_all_profiles().unwrap();
+        assert_eq!(report.loaded.len(), 255);
+        assert_eq!(report.recovered, vec![(5, tarball.clone())]);
+        assert_eq!(mgr.get_profile(5).name(), "Backup");
+
+        std::fs::remove_file(&tarball).unwrap();
+        std::fs::write(mgr.profile_path(6), "").unwrap();
+        let report = mgr.load_all_profiles().unwrap();
+        assert_eq!(report.reset, vec![6]);
+        assert!(std::path::Path::new(&format!("{}.corrupt", mgr.profile_path(6))).exists());
+    }
+}
@@ -0,0 +1,161 @@
+use super::{
+    Mutex, SeamParamsPublisher, SeamProfileError, SeamProfileManager, SeamProfileManagerLock,
+    SeamProfileManagerOptions, SEAM_PROFILE_MANAGER,
+};
+use log::info;
+use std::collections::BTreeMap;
+use std::sync::{Arc, LazyLock, OnceLock};
+
+/// 已注册的配置管理器实例，以传感器编号等名称为键。
+static SEAM_PROFILE_INSTANCES: LazyLock<Mutex<BTreeMap<String, Arc<SeamProfileManagerLock>>>> =
+    LazyLock::new(Default::default);
+
+impl SeamProfileManager {
+    /// 以指定目录及选项初始化全局配置管理器。
+    ///
+    /// 必须在首次调用 [`SeamProfileManager::global`] 之前调用，否则返回
+    /// [`SeamProfileError::AlreadyInitialized`]。
+    pub fn init_global<S: Into<String>>(
+        backup_dir: S,
+        config_dir: S,
+        options: SeamProfileManagerOptions,
+    ) -> Result<Arc<SeamProfileManagerLock>, SeamProfileError> {
+        if SEAM_PROFILE_MANAGER.get().is_some() {
+            return Err(SeamProfileError::AlreadyInitialized);
+        }
+        let mgr = Arc::new(SeamProfileManagerLock::new(Self::try_with_options(
+            backup_dir, config_dir, options,
+        )?));
+        SEAM_PROFILE_MANAGER
+            .set(mgr.clone())
+            .map_err(|_| SeamProfileError::AlreadyInitialized)?;
+        Ok(mgr)
+    }
+
+    /// 全局配置管理器是否已经初始化。
+    pub fn is_global_initialized() -> bool {
+        SEAM_PROFILE_MANAGER.get().is_some()
+    }
+
+    /// 以指定名称注册配置管理器实例，名称已被占用时返回错误。
+    pub fn register_instance<S: Into<String>>(
+        name: S,
+        mgr: Self,
+    ) -> Result<Arc<SeamProfileManagerLock>, SeamProfileError> {
+        let name = name.into();
+        let mut instances = SEAM_PROFILE_INSTANCES.lock().unwrap();
+        if instances.contains_key(&name) {
+            return Err(SeamProfileError::InstanceExists(name));
+        }
+        let mgr = Arc::new(SeamProfileManagerLock::new(mgr));
+        instances.insert(name.clone(), mgr.clone());
+        info!("配置管理器实例 {} 已经注册", name);
+        Ok(mgr)
+    }
+
+    /// 返回指定名称的配置管理器实例。
+    pub fn instance(name: &str) -> Option<Arc<SeamProfileManagerLock>> {
+        SEAM_PROFILE_INSTANCES.lock().unwrap().get(name).cloned()
+    }
+
+    /// 注销指定名称的配置管理器实例，已取得的共享引用仍然有效。
+    pub fn remove_instance(name: &str) -> Option<Arc<SeamProfileManagerLock>> {
+        let mgr = SEAM_PROFILE_INSTANCES.lock().unwrap().remove(name);
+        if mgr.is_some() {
+            info!("配置管理器实例 {} 已经注销", name);
+        }
+        mgr
+    }
+
+    /// 按名称顺序返回所有已注册实例的名称。
+    pub fn instance_names() -> Vec<String> {
+        SEAM_PROFILE_INSTANCES
+            .lock()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect()
+    }
+}
+
+/// 一个代表 C 接口中配置管理器句柄的类型。
+///
+/// 句柄持有管理器及其发布器的共享引用，读取发布的参数表时无需获取管理器锁。
+pub struct SeamProfileManagerHandle {
+    manager: Arc<SeamProfileManagerLock>,
+    publisher: Arc<SeamParamsPublisher>,
+}
+
+impl SeamProfileManagerHandle {
+    /// 创建指定配置管理器的句柄。
+    pub fn new(manager: Arc<SeamProfileManagerLock>) -> Self {
+        let publisher = SeamProfileManager::lock_blocking(&manager)
+            .publisher()
+            .clone();
+        Self { manager, publisher }
+    }
+
+    /// 返回全局配置管理器的句柄。
+    pub fn global() -> &'static Self {
+        static HANDLE: OnceLock<SeamProfileManagerHandle> = OnceLock::new();
+        HANDLE.get_or_init(|| Self::new(SeamProfileManager::global_arc()))
+    }
+
+    /// 返回配置管理器。
+    pub fn manager(&self) -> &Arc<SeamProfileManagerLock> {
+        &self.manager
+    }
+
+    /// 返回当前生效参数表的发布器。
+    pub fn publisher(&self) -> &Arc<SeamParamsPublisher> {
+        &self.publisher
+    }
+
+    /// 是否为全局配置管理器的句柄。
+    pub fn is_global(&self) -> bool {
+        std::ptr::eq(self, Self::global())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_registry() {
+        let dir = TestDir::new("registry");
+        let dir = dir.to_str();
+        let head1_dir = format!("{}/head1", dir);
+        let head2_dir = format!("{}/head2", dir);
+        let mgr = SeamProfileManager::new(&head1_dir, &head1_dir);
+        let head1 = SeamProfileManager::register_instance("test-head1", mgr).unwrap();
+        let mgr = SeamProfileManager::new(&head2_dir, &head2_dir);
+        let head2 = SeamProfileManager::register_instance("test-head2", mgr).unwrap();
+        assert!(Arc::ptr_eq(
+            &SeamProfileManager::instance("test-head1").unwrap(),
+            &head1
+        ));
+        let names = SeamProfileManager::instance_names();
+        assert!(names.contains(&"test-head1".to_string()));
+        assert!(names.contains(&"test-head2".to_string()));
+
+        let mgr = SeamProfileManager::new(&head1_dir, &head1_dir);
+        assert!(matches!(
+            SeamProfileManager::register_instance("test-head1", mgr),
+            Err(SeamProfileError::InstanceExists(_))
+        ));
+
+        let handle = SeamProfileManagerHandle::new(head2.clone());
+        assert!(!handle.is_global());
+        SeamProfileManager::lock_blocking(&head2)
+            .switch_profile(3)
+            .unwrap();
+        assert_eq!(handle.publisher().load().profile_id, 3);
+        assert_eq!(head1.try_lock().unwrap().current_profile_id(), 0);
+
+        assert!(SeamProfileManager::remove_instance("test-head1").is_some());
+        assert!(SeamProfileManager::remove_instance("test-head2").is_some());
+        assert!(SeamProfileManager::instance("test-head1").is_none());
+    }
+}
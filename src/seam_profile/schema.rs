@@ -0,0 +1,348 @@
+use super::{
+    seam_param_descs, write_atomic, SeamParamArea, SeamParamDesc, SeamParamTypedValue,
+    SeamProfileError, SeamProfileManager, SEAM_PROFILES_META_ONLY_SCHEMA, SEAM_PROFILES_SCHEMA,
+    SEAM_PROFILE_SCHEMA,
+};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+/// JSON Schema 规范版本。
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// 一个代表配置档案格式的枚举，与档案规范一一对应。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeamSchemaKind {
+    /// 单个配置，见 [`SEAM_PROFILE_SCHEMA`]。
+    Profile,
+    /// 配置列表，见 [`SEAM_PROFILES_SCHEMA`]。
+    Profiles,
+    /// 简化的配置信息列表，见 [`SEAM_PROFILES_META_ONLY_SCHEMA`]。
+    ProfilesMetaOnly,
+}
+
+impl SeamSchemaKind {
+    /// 所有格式。
+    pub const ALL: [SeamSchemaKind; 3] = [
+        SeamSchemaKind::Profile,
+        SeamSchemaKind::Profiles,
+        SeamSchemaKind::ProfilesMetaOnly,
+    ];
+
+    /// 返回档案规范。
+    pub fn url(self) -> &'static str {
+        match self {
+            SeamSchemaKind::Profile => SEAM_PROFILE_SCHEMA,
+            SeamSchemaKind::Profiles => SEAM_PROFILES_SCHEMA,
+            SeamSchemaKind::ProfilesMetaOnly => SEAM_PROFILES_META_ONLY_SCHEMA,
+        }
+    }
+
+    /// 以档案规范查找格式。
+    pub fn from_url(url: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.url() == url)
+    }
+
+    /// 返回 JSON Schema 文件名，如 `seam-profile.json`。
+    pub fn file_name(self) -> &'static str {
+        self.url().rsplit('/').next().unwrap_or_default()
+    }
+
+    /// 生成本格式的 JSON Schema 文档。
+    pub fn json_schema(self) -> Value {
+        let mut doc = match self {
+            SeamSchemaKind::Profile => json!({
+                "title": "接头识别参数配置",
+                "$ref": "#/$defs/profile",
+            }),
+            SeamSchemaKind::Profiles => json!({
+                "title": "接头识别参数配置列表",
+                "type": "object",
+                "properties": {
+                    "schema": schema_url(self),
+                    "profiles": {"type": "array", "items": {"$ref": "#/$defs/profile"}},
+                },
+                "required": ["profiles"],
+            }),
+            SeamSchemaKind::ProfilesMetaOnly => json!({
+                "title": "简化的接头识别参数配置信息列表",
+                "type": "object",
+                "properties": {
+                    "schema": schema_url(self),
+                    "profiles": {"type": "array", "items": {"$ref": "#/$defs/profileMetaOnly"}},
+                },
+                "required": ["profiles"],
+            }),
+        };
+        doc["$schema"] = JSON_SCHEMA_DIALECT.into();
+        doc["$id"] = self.url().into();
+        doc["$defs"] = defs();
+        doc
+    }
+}
+
+/// 档案规范字段，为空时视为旧版配置。
+fn schema_url(kind: SeamSchemaKind) -> Value {
+    json!({"type": "string", "enum": [kind.url(), ""]})
+}
+
+/// 原始数值数组形式的参数表。
+fn values_schema(n: usize) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "values": {
+                "type": "array",
+                "maxItems": n,
+                "items": {"type": "integer", "minimum": i32::MIN, "maximum": i32::MAX},
+            },
+        },
+        "required": ["values"],
+        "additionalProperties": false,
+    })
+}
+
+/// 单个参数的取值范围。
+fn param_schema(desc: &SeamParamDesc) -> Value {
+    let mut schema = if desc.choices.is_empty() {
+        let ty = match desc.min {
+            SeamParamTypedValue::I32(_) => "integer",
+            SeamParamTypedValue::F32(_) => "number",
+        };
+        json!({
+            "type": ty,
+            "minimum": desc.to_json(desc.min),
+            "maximum": desc.to_json(desc.max),
+        })
+    } else {
+        let mut choices: Vec<Value> = desc.choices.iter().map(|c| c.value.into()).collect();
+        choices.extend(desc.choices.iter().map(|c| Value::from(c.name)));
+        json!({"enum": choices})
+    };
+    schema["title"] = desc.label.into();
+    schema["default"] = desc.to_json(desc.default);
+    if !desc.unit.is_empty() {
+        schema["description"] = format!("单位：{}", desc.unit).into();
+    }
+    schema
+}
+
+/// 命名形式的参数表。
+fn named_schema() -> Value {
+    let mut areas = Map::new();
+    for area in SeamParamArea::ALL {
+        let params: Map<String, Value> = seam_param_descs()
+            .iter()
+            .filter(|d| d.area == area)
+            .map(|d| (d.area_name.clone(), param_schema(d)))
+            .collect();
+        areas.insert(
+            area.name().into(),
+            json!({"type": "object", "properties": params, "additionalProperties": false}),
+        );
+    }
+    let mut en = Map::new();
+    for area in SeamParamArea::ALL {
+        if area.en_ids().is_empty() {
+            continue;
+        }
+        let names: Vec<&str> = area
+            .flat_ids()
+            .filter_map(|id| id.desc())
+            .map(|d| d.area_name.as_str())
+            .collect();
+        en.insert(
+            area.name().into(),
+            json!({"type": "array", "uniqueItems": true, "items": {"enum": names}}),
+        );
+    }
+    areas.insert(
+        "en".into(),
+        json!({
+            "title": "使能的参数名称列表",
+            "type": "object",
+            "properties": en,
+            "additionalProperties": false,
+        }),
+    );
+    json!({
+        "type": "object",
+        "properties": areas,
+        "additionalProperties": false,
+    })
+}
+
+/// 所有格式共用的定义。
+fn defs() -> Value {
+    json!({
+        "meta": {
+            "type": "object",
+            "properties": {
+                "name": {"type": "string"},
+                "jointType": {"type": "integer"},
+                "jointTypeMajor": {"type": "integer"},
+                "jointTypeMinor": {"type": "integer"},
+                "version": {"type": "integer"},
+            },
+            "required": ["name", "jointType", "jointTypeMajor", "jointTypeMinor", "version"],
+        },
+        "profile": {
+            "type": "object",
+            "properties": {
+                "schema": schema_url(SeamSchemaKind::Profile),
+                "enabled": {"type": "boolean"},
+                "id": {"type": "integer", "minimum": 0},
+                "meta": {"$ref": "#/$defs/meta"},
+                "v0": {"anyOf": [values_schema(250), named_schema()]},
+                "v1": values_schema(350),
+            },
+            "required": ["enabled", "id", "meta", "v0"],
+        },
+        "profileMetaOnly": {
+            "type": "object",
+            "properties": {
+                "enabled": {"type": "boolean"},
+                "id": {"type": "integer", "minimum": 0},
+                "meta": {"$ref": "#/$defs/meta"},
+            },
+            "required": ["enabled", "id", "meta"],
+        },
+    })
+}
+
+/// 将所有格式的 JSON Schema 文档写入指定目录，返回写入的文件数量。
+pub fn write_json_schemas<P: AsRef<Path>>(dir: P) -> Result<usize, SeamProfileError> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+    for kind in SeamSchemaKind::ALL {
+        let text = serde_json::to_string_pretty(&kind.json_schema())?;
+        write_atomic(dir.join(kind.file_name()), text)?;
+    }
+    Ok(SeamSchemaKind::ALL.len())
+}
+
+/// 简化的配置信息，仅读取使能状态及名称。
+#[derive(Deserialize)]
+struct SeamProfileMetaOnlyOwned {
+    enabled: bool,
+    id: i32,
+    meta: SeamProfileMetaName,
+}
+
+/// 配置元数据中的名称。
+#[derive(Deserialize)]
+struct SeamProfileMetaName {
+    name: String,
+}
+
+/// 简化的配置信息列表。
+#[derive(Deserialize)]
+struct SeamProfilesMetaOnlyOwned {
+    profiles: Vec<SeamProfileMetaOnlyOwned>,
+}
+
+impl SeamProfileManager {
+    /// 按档案规范加载任一格式的 JSON 数据，返回涉及的格式。
+    ///
+    /// 缺少档案规范时按是否包含 `profiles` 判断格式；
+    /// 简化的配置信息列表仅更新使能状态及名称，所有条目均有效时才一次性生效。
+    pub fn load_json_str(&mut self, json: &str) -> Result<SeamSchemaKind, SeamProfileError> {
+        let value: Value = serde_json::from_str(json)?;
+        let kind = match value.get("schema").and_then(Value::as_str).unwrap_or("") {
+            "" if value.get("profiles").is_some() => SeamSchemaKind::Profiles,
+            "" => SeamSchemaKind::Profile,
+            url => SeamSchemaKind::from_url(url)
+                .ok_or_else(|| SeamProfileError::UnknownSchema(url.into()))?,
+        };
+        match kind {
+            SeamSchemaKind::Profile => self.load_profile_from_json_str(json)?,
+            SeamSchemaKind::Profiles => self.load_profiles_from_json_str(json)?,
+            SeamSchemaKind::ProfilesMetaOnly => {
+                let info = serde_json::from_value::<SeamProfilesMetaOnlyOwned>(value)?;
+                let updated = info
+                    .profiles
+                    .into_iter()
+                    .map(|p| {
+                        let mut profile = self.patch_base(p.id)?;
+                        profile.enabled = p.enabled;
+                        profile.meta.set_name(p.meta.name);
+                        Ok(profile)
+                    })
+                    .collect::<Result<Vec<_>, SeamProfileError>>()?;
+                // 仅修改使能状态及名称，参数表不变，无需重新校验。
+                for profile in &updated {
+                    self.update_profile(profile);
+                }
+            }
+        }
+        Ok(kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::SeamParamFlatId;
+
+    #[test]
+    fn test_json_schema() {
+        let doc = SeamSchemaKind::Profiles.json_schema();
+        assert_eq!(doc["$id"], SEAM_PROFILES_SCHEMA);
+        let kp = &doc["$defs"]["profile"]["properties"]["v0"]["anyOf"][1]["properties"]["kp"];
+        assert_eq!(kp["properties"]["Angle1"]["type"], "number");
+        let op = &doc["$defs"]["profile"]["properties"]["v0"]["anyOf"][1]["properties"]["op"];
+        assert_eq!(op["properties"]["BevelAngle1"]["type"], "integer");
+        assert!(op["properties"]["TrackPos"].is_object());
+        assert_eq!(
+            SeamSchemaKind::from_url(SEAM_PROFILES_META_ONLY_SCHEMA).map(|k| k.file_name()),
+            Some("seam-profiles-meta-only.json")
+        );
+    }
+
+    #[test]
+    fn test_load_json_dispatch() {
+        let dir = TestDir::new("schema");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        mgr.get_profile_mut(3)
+            .v0
+            .set(SeamParamFlatId::XpExposureTime, -1.0f32);
+        let meta = json!({
+            "schema": SEAM_PROFILES_META_ONLY_SCHEMA,
+            "profiles": [{"enabled": true, "id": 3, "meta": {"name": "Renamed"}}],
+        });
+        assert_eq!(
+            mgr.load_json_str(&meta.to_string()).unwrap(),
+            SeamSchemaKind::ProfilesMetaOnly
+        );
+        assert!(mgr.get_profile(3).is_enabled());
+        assert_eq!(mgr.get_profile(3).name(), "Renamed");
+        let meta = json!({
+            "schema": SEAM_PROFILES_META_ONLY_SCHEMA,
+            "profiles": [
+                {"enabled": true, "id": 2, "meta": {"name": "First"}},
+                {"enabled": true, "id": 4096, "meta": {"name": "Invalid"}},
+            ],
+        });
+        assert!(mgr.load_json_str(&meta.to_string()).is_err());
+        assert_eq!(mgr.get_profile(2).name(), "");
+        mgr.get_profile_mut(3)
+            .v0
+            .set(SeamParamFlatId::XpExposureTime, 1000);
+
+        let json = mgr.dump_profiles_string(Some(3)).unwrap();
+        assert_eq!(mgr.load_json_str(&json).unwrap(), SeamSchemaKind::Profiles);
+        let mut profile = serde_json::to_value(mgr.get_profile(3)).unwrap();
+        profile["schema"] = "".into();
+        profile["meta"]["name"] = "Legacy".into();
+        let kind = mgr.load_json_str(&profile.to_string()).unwrap();
+        assert_eq!(kind, SeamSchemaKind::Profile);
+        assert_eq!(mgr.get_profile(3).name(), "Legacy");
+        let unknown = json!({"schema": "https://example.com/other.json"});
+        assert!(matches!(
+            mgr.load_json_str(&unknown.to_string()),
+            Err(SeamProfileError::UnknownSchema(_))
+        ));
+    }
+}
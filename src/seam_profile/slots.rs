@@ -0,0 +1,298 @@
+use super::{
+    SeamParamsMigration, SeamParamsV0, SeamProfile, SeamProfileError, SeamProfileEvent,
+    SeamProfileManager,
+};
+use log::{info, warn};
+
+/// 默认的配置槽位数量。
+pub const DEFAULT_PROFILE_SLOTS: usize = 256;
+
+/// 一个代表配置管理器选项的类型。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamProfileManagerOptions {
+    /// 配置槽位数量，配置编号为 `0..slots`。
+    pub slots: usize,
+    /// 是否为稀疏模式。
+    ///
+    /// 稀疏模式下仅已创建的配置保存为文件，缺失的文件不会自动创建，对未创建配置的修改不会保存；
+    /// 加载后当前生效的配置始终为已创建的配置。否则每个槽位均对应一个配置文件，
+    /// 已启用或名称、参数不同于默认值的配置视为已创建，不会被创建或复制的配置覆盖。
+    pub sparse: bool,
+}
+
+impl Default for SeamProfileManagerOptions {
+    fn default() -> Self {
+        Self {
+            slots: DEFAULT_PROFILE_SLOTS,
+            sparse: false,
+        }
+    }
+}
+
+impl SeamProfileManager {
+    /// 返回配置槽位数量。
+    pub fn slots(&self) -> usize {
+        self.profiles.len()
+    }
+
+    /// 是否为稀疏模式。
+    pub fn is_sparse(&self) -> bool {
+        self.sparse
+    }
+
+    /// 指定编号的配置是否已创建。
+    pub fn profile_exists(&self, id: usize) -> bool {
+        match self.profiles.get(id) {
+            Some(_) if self.sparse => self.existing[id],
+            Some(profile) => profile.is_enabled() || !Self::is_blank(id, profile),
+            None => false,
+        }
+    }
+
+    /// 配置是否与同一槽位中新建的禁用配置相同。
+    fn is_blank(id: usize, profile: &SeamProfile) -> bool {
+        profile.diff(&SeamProfile::new(false, id as i32)).is_empty()
+    }
+
+    /// 返回所有已创建配置的编号。
+    pub fn profile_ids(&self) -> Vec<usize> {
+        (0..self.slots())
+            .filter(|&id| self.profile_exists(id))
+            .collect()
+    }
+
+    /// 在指定槽位以默认参数创建配置。
+    pub fn create_profile<S: Into<String>>(
+        &mut self,
+        id: usize,
+        name: S,
+    ) -> Result<(), SeamProfileError> {
+        self.check_free_slot(id)?;
+        let mut profile = SeamProfile::new(true, id as i32);
+        profile.v0 = SeamParamsV0::with_defaults();
+        profile.v1_mut().migrate()?;
+        profile.sync_meta();
+        profile.meta.set_name(name);
+        self.put_profile(id, &profile);
+        info!("配置 #{} 已经创建", id);
+        self.emit(SeamProfileEvent::ProfileCreated { profile_id: id });
+        Ok(())
+    }
+
+    /// 删除指定编号的配置，当前生效的配置不能删除。
+    ///
+    /// 稀疏模式下同时删除配置文件，否则将配置重置为默认值并禁用，之后该槽位可重新创建配置。
+    pub fn delete_profile(&mut self, id: usize) -> Result<(), SeamProfileError> {
+        self.check_exists(id)?;
+        if id == self.current_index {
+            return Err(SeamProfileError::ProfileInUse(id as i32));
+        }
+        self.put_profile(id, &SeamProfile::new(false, id as i32));
+        if self.sparse {
+            self.existing[id] = false;
+            self.profiles[id].flush();
+            match std::fs::remove_file(self.profile_path(id)) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err.into()),
+                _ => {}
+            }
+        }
+        info!("配置 #{} 已经删除", id);
+        self.emit(SeamProfileEvent::ProfileDeleted { profile_id: id });
+        Ok(())
+    }
+
+    /// 将配置复制到另一个未创建的槽位，包括名称及所有参数。
+    pub fn copy_profile(&mut self, src: usize, dst: usize) -> Result<(), SeamProfileError> {
+        self.check_exists(src)?;
+        self.check_free_slot(dst)?;
+        let mut profile = SeamProfile::new(true, dst as i32);
+        profile.merge_with_commit(&self.profiles[src]);
+        profile.enabled = true;
+        self.put_profile(dst, &profile);
+        info!("配置 #{} 已经复制到 #{}", src, dst);
+        self.emit(SeamProfileEvent::ProfileCreated { profile_id: dst });
+        Ok(())
+    }
+
+    /// 重命名指定编号的配置。
+    pub fn rename_profile<S: Into<String>>(
+        &mut self,
+        id: usize,
+        name: S,
+    ) -> Result<(), SeamProfileError> {
+        self.check_exists(id)?;
+        self.profiles[id].set_name(name);
+        self.profiles_modified.set_now();
+        self.commit();
+        Ok(())
+    }
+
+    /// 切换到指定编号的配置，配置不存在时返回错误。
+    pub fn switch_profile(&mut self, id: usize) -> Result<(), SeamProfileError> {
+        if self.sparse {
+            self.check_exists(id)?;
+        } else {
+            self.try_get_profile(id)?;
+        }
+        self.set_current_profile_id(id);
+        Ok(())
+    }
+
+    /// 稀疏模式下当前生效的配置未创建时，切换到编号最小的已创建配置。
+    ///
+    /// 没有任何已创建的配置时，在当前槽位以默认参数创建配置，返回其编号，由调用方保存。
+    pub(super) fn ensure_current_exists(&mut self) -> Result<Option<usize>, SeamProfileError> {
+        let id = self.current_index;
+        if !self.sparse || self.existing[id] {
+            return Ok(None);
+        }
+        if let Some(&first) = self.profile_ids().first() {
+            warn!("当前配置 #{} 不存在，切换到配置 #{}", id, first);
+            self.set_current_profile_id(first);
+            return Ok(None);
+        }
+        warn!("没有已创建的配置，创建默认配置 #{} ...", id);
+        self.create_profile(id, "")?;
+        Ok(Some(id))
+    }
+
+    /// 丢弃对未创建配置所做的变更。
+    pub(super) fn discard_changes(&self, id: usize) {
+        warn!("配置 #{} 不存在，丢弃对其所做的变更", id);
+        self.emit(SeamProfileEvent::ChangesDiscarded { profile_id: id });
+    }
+
+    /// 检查配置是否已创建。
+    pub(super) fn check_exists(&self, id: usize) -> Result<(), SeamProfileError> {
+        self.try_get_profile(id)?;
+        if self.profile_exists(id) {
+            Ok(())
+        } else {
+            Err(SeamProfileError::NoSuchProfile(id as i32))
+        }
+    }
+
+    /// 检查槽位是否空闲。
+    pub(super) fn check_free_slot(&self, id: usize) -> Result<(), SeamProfileError> {
+        self.try_get_profile(id)?;
+        if self.profile_exists(id) {
+            Err(SeamProfileError::ProfileExists(id as i32))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 以新配置整体替换指定槽位中的配置并标记为已创建。
+    pub(super) fn put_profile(&mut self, id: usize, profile: &SeamProfile) {
+        self.profiles[id].merge(profile);
+        self.profiles_ffi[id].enabled = profile.enabled as i32;
+        self.existing[id] = true;
+        self.profiles_modified.set_now();
+        self.commit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_sparse_profiles() {
+        let dir = TestDir::new("slots");
+        let dir = dir.to_str();
+        let options = SeamProfileManagerOptions {
+            slots: 8,
+            sparse: true,
+        };
+        let mut mgr = SeamProfileManager::try_with_options(
+            format!("{}/backup", dir),
+            format!("{}/config", dir),
+            options,
+        )
+        .unwrap();
+        let report = mgr.load_all_profiles().unwrap();
+        assert_eq!(report.created, vec![0]);
+        assert_eq!(mgr.slots(), 8);
+        assert_eq!(mgr.profile_ids(), vec![0]);
+
+        mgr.create_profile(2, "Butt").unwrap();
+        assert!(matches!(
+            mgr.create_profile(2, "Again"),
+            Err(SeamProfileError::ProfileExists(2))
+        ));
+        mgr.copy_profile(2, 5).unwrap();
+        mgr.rename_profile(5, "Copy").unwrap();
+        mgr.flush().unwrap();
+        assert_eq!(mgr.load_profile(5).unwrap().name(), "Copy");
+        assert!(!std::path::Path::new(&mgr.profile_path(3)).exists());
+
+        mgr.switch_profile(5).unwrap();
+        assert!(mgr.switch_profile(3).is_err());
+        assert!(matches!(
+            mgr.delete_profile(5),
+            Err(SeamProfileError::ProfileInUse(5))
+        ));
+        mgr.delete_profile(2).unwrap();
+        mgr.delete_profile(0).unwrap();
+        mgr.flush().unwrap();
+        assert!(!std::path::Path::new(&mgr.profile_path(2)).exists());
+
+        let discarded = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = discarded.clone();
+        mgr.subscribe(move |e| {
+            if let SeamProfileEvent::ChangesDiscarded { profile_id } = e {
+                sink.lock().unwrap().push(*profile_id);
+            }
+        });
+        mgr.get_profile_mut(3).set_name("Missing");
+        mgr.commit();
+        mgr.flush().unwrap();
+        assert_eq!(*discarded.lock().unwrap(), vec![3]);
+        assert!(!std::path::Path::new(&mgr.profile_path(3)).exists());
+
+        let mut reloaded = SeamProfileManager::with_options(
+            mgr.backup_dir.clone(),
+            mgr.config_dir.clone(),
+            options,
+        );
+        reloaded.load_all_profiles().unwrap();
+        assert_eq!(reloaded.profile_ids(), vec![5]);
+        assert_eq!(reloaded.current_profile_id(), 5);
+        assert!(reloaded.try_get_profile(8).is_err());
+    }
+
+    #[test]
+    fn test_dense_profile_slots() {
+        let dir = TestDir::new("slots-dense");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        mgr.load_all_profiles().unwrap();
+        mgr.create_profile(3, "Ext").unwrap();
+        assert!(mgr.get_profile_mut(3).v1_mut().set_ap(0, 42.into()));
+        mgr.commit();
+        mgr.flush().unwrap();
+
+        let mut reloaded = SeamProfileManager::new(mgr.backup_dir.clone(), mgr.config_dir.clone());
+        reloaded.load_all_profiles().unwrap();
+        let profile = reloaded.try_get_profile(3).unwrap();
+        assert_eq!(profile.v1().ap(0), Some(42.into()));
+        assert_eq!(profile.v1().version(), 1);
+        assert!(profile.is_meta_consistent());
+
+        mgr.get_profile_mut(4).set_name("Tuned");
+        mgr.commit();
+        assert!(mgr.profile_exists(4));
+        assert!(matches!(
+            mgr.create_profile(4, "New"),
+            Err(SeamProfileError::ProfileExists(4))
+        ));
+        assert!(matches!(
+            mgr.copy_profile(3, 4),
+            Err(SeamProfileError::ProfileExists(4))
+        ));
+        mgr.delete_profile(4).unwrap();
+        assert!(!mgr.profile_exists(4));
+        mgr.copy_profile(3, 4).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+use super::{
+    JointType, SeamParamFlatId, SeamParamTypedValue, SeamParamsMigration, SeamParamsV0,
+    SeamProfile, SeamProfileError, SeamProfileEvent, SeamProfileManager,
+};
+use log::info;
+
+/// 一个代表配置模板的类型。
+#[derive(Debug)]
+pub struct SeamProfileTemplate {
+    /// 接头类型。
+    pub joint_type: JointType,
+    /// 在参数默认值基础上修改的参数。
+    pub params: &'static [(SeamParamFlatId, SeamParamTypedValue)],
+}
+
+impl SeamProfileTemplate {
+    /// 返回模板对应的参数表。
+    pub fn params(&self) -> SeamParamsV0 {
+        let mut v0 = SeamParamsV0::with_defaults();
+        for &(id, value) in self.params {
+            v0.set(id, value);
+        }
+        v0.set_typed_joint_type(self.joint_type);
+        v0
+    }
+}
+
+/// 内置的配置模板。
+///
+/// 模板参数须来自现场标定或算法组提供的数据，暂无可用数据，因此尚未内置任何模板。
+static SEAM_PROFILE_TEMPLATES: &[SeamProfileTemplate] = &[];
+
+/// 返回所有内置的配置模板，按接头类型排列。
+pub fn seam_profile_templates() -> &'static [SeamProfileTemplate] {
+    SEAM_PROFILE_TEMPLATES
+}
+
+/// 返回指定接头类型的内置模板。
+pub fn seam_profile_template(joint_type: JointType) -> Option<&'static SeamProfileTemplate> {
+    SEAM_PROFILE_TEMPLATES
+        .iter()
+        .find(|t| t.joint_type == joint_type)
+}
+
+impl SeamProfileManager {
+    /// 以指定接头类型的内置模板在空闲槽位中创建配置。
+    pub fn create_from_template<S: Into<String>>(
+        &mut self,
+        joint_type: JointType,
+        slot: usize,
+        name: S,
+    ) -> Result<(), SeamProfileError> {
+        let template = seam_profile_template(joint_type)
+            .ok_or(SeamProfileError::NoSuchTemplate(joint_type.code()))?;
+        self.check_free_slot(slot)?;
+        let mut profile = SeamProfile::new(true, slot as i32);
+        profile.meta.set_name(name);
+        profile.v0 = template.params();
+        profile.v1_mut().migrate()?;
+        self.check_profile(&profile)?;
+        self.put_profile(slot, &profile);
+        info!("配置 #{} 已经以 {} 模板创建", slot, template.joint_type);
+        self.emit(SeamProfileEvent::ProfileCreated { profile_id: slot });
+        Ok(())
+    }
+
+    /// 将配置复制到编号最小的空闲槽位，返回新配置的编号。
+    pub fn clone_profile(&mut self, src: usize) -> Result<usize, SeamProfileError> {
+        let dst = (0..self.slots())
+            .find(|&id| !self.profile_exists(id))
+            .ok_or(SeamProfileError::NoFreeSlot)?;
+        self.copy_profile(src, dst)?;
+        Ok(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_templates() {
+        let template = SeamProfileTemplate {
+            joint_type: JointType::GrooveV,
+            params: &[],
+        };
+        let v0 = template.params();
+        assert_eq!(v0.typed_joint_type(), Some(JointType::GrooveV));
+        assert_eq!(v0.joint_type(), 0x0301);
+        assert!(seam_profile_template(JointType::GrooveV).is_none());
+
+        let dir = TestDir::new("templates");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        assert!(matches!(
+            mgr.create_from_template(JointType::GrooveV, 4, "Groove"),
+            Err(SeamProfileError::NoSuchTemplate(0x31))
+        ));
+        assert!(!mgr.profile_exists(4));
+
+        mgr.create_profile(4, "Groove").unwrap();
+        mgr.enable_profile(0);
+        let id = mgr.clone_profile(4).unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(mgr.get_profile(id).name(), "Groove");
+        assert_eq!(mgr.get_profile(id).id(), 1);
+    }
+}
@@ -0,0 +1,309 @@
+use super::{
+    check_schema, SeamParamArea, SeamParamValue, SeamParamsV0, SeamProfile, SeamProfileError,
+    SEAM_PROFILE_SCHEMA,
+};
+use serde::de::Deserializer;
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::mem::{offset_of, size_of};
+
+/// V1 版扩展参数表中的参数数量。
+const EXT_V1_NUM: usize = 350;
+
+/// 一个代表 V1 版扩展参数分区表的类型。
+///
+/// 原有分区在 V0 版参数之后各扩展一倍，另新增 AP 分区；扩展 SF 分区的最后一项记录参数表布局版本。
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct SeamParamsExtV1 {
+    pub xp: [SeamParamValue; 30],
+    pub kp: [SeamParamValue; 30],
+    pub op: [SeamParamValue; 60],
+    pub vp: [SeamParamValue; 60],
+    pub oc: [SeamParamValue; 60],
+    pub sf: [SeamParamValue; 10],
+    /// 新算法参数。
+    pub ap: [SeamParamValue; 100],
+}
+
+unsafe impl Send for SeamParamsExtV1 {}
+unsafe impl Sync for SeamParamsExtV1 {}
+
+impl Default for SeamParamsExtV1 {
+    fn default() -> Self {
+        Self {
+            xp: [Default::default(); 30],
+            kp: [Default::default(); 30],
+            op: [Default::default(); 60],
+            vp: [Default::default(); 60],
+            oc: [Default::default(); 60],
+            sf: [Default::default(); 10],
+            ap: [Default::default(); 100],
+        }
+    }
+}
+
+impl AsRef<[i32]> for SeamParamsExtV1 {
+    fn as_ref(&self) -> &[i32] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const i32, EXT_V1_NUM) }
+    }
+}
+
+impl AsMut<[i32]> for SeamParamsExtV1 {
+    fn as_mut(&mut self) -> &mut [i32] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut i32, EXT_V1_NUM) }
+    }
+}
+
+impl Serialize for SeamParamsExtV1 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let values: &[i32] = self.as_ref();
+        let mut ext = serializer.serialize_struct("SeamParamsExtV1", 1)?;
+        ext.serialize_field("values", values)?;
+        ext.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SeamParamsExtV1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            values: Vec<i32>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let n = raw.values.len();
+        if n > EXT_V1_NUM {
+            return Err(serde::de::Error::invalid_length(
+                n,
+                &"at most 350 extension values",
+            ));
+        }
+        let mut ext = SeamParamsExtV1::default();
+        let dst: &mut [i32] = ext.as_mut();
+        dst[..n].copy_from_slice(&raw.values);
+        Ok(ext)
+    }
+}
+
+impl SeamParamsExtV1 {
+    /// 返回记录的参数表布局版本。
+    pub fn version(&self) -> i32 {
+        unsafe { self.sf[9].i32_val }
+    }
+
+    fn area(&self, area: SeamParamArea) -> &[SeamParamValue] {
+        match area {
+            SeamParamArea::Xp => &self.xp,
+            SeamParamArea::Kp => &self.kp,
+            SeamParamArea::Op => &self.op,
+            SeamParamArea::Vp => &self.vp,
+            SeamParamArea::Oc => &self.oc,
+            SeamParamArea::Sf => &self.sf,
+        }
+    }
+
+    fn area_mut(&mut self, area: SeamParamArea) -> &mut [SeamParamValue] {
+        match area {
+            SeamParamArea::Xp => &mut self.xp,
+            SeamParamArea::Kp => &mut self.kp,
+            SeamParamArea::Op => &mut self.op,
+            SeamParamArea::Vp => &mut self.vp,
+            SeamParamArea::Oc => &mut self.oc,
+            SeamParamArea::Sf => &mut self.sf,
+        }
+    }
+}
+
+/// 一个代表 V1 版接头识别参数表的类型。
+///
+/// 前半部分与 V0 版参数表布局相同，仅读取 V0 版参数的程序不受影响。
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct SeamParamsV1 {
+    /// V0 版参数表。
+    pub v0: SeamParamsV0,
+    /// V1 版扩展参数表。
+    pub ext: SeamParamsExtV1,
+}
+
+const _: () = assert!(size_of::<SeamParamsV1>() == (250 + EXT_V1_NUM) * 4);
+const _: () = assert!(
+    offset_of!(SeamProfile, v1_ext) == offset_of!(SeamProfile, v0) + size_of::<SeamParamsV0>()
+);
+
+impl AsRef<[i32]> for SeamParamsV1 {
+    fn as_ref(&self) -> &[i32] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const i32, 250 + EXT_V1_NUM) }
+    }
+}
+
+impl AsMut<[i32]> for SeamParamsV1 {
+    fn as_mut(&mut self) -> &mut [i32] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut i32, 250 + EXT_V1_NUM) }
+    }
+}
+
+impl SeamParamsV1 {
+    /// 返回指定分区中的参数数量。
+    pub fn area_count(area: SeamParamArea) -> usize {
+        area.count() * 2
+    }
+
+    /// 返回扩展参数表中记录的布局版本。
+    pub fn version(&self) -> i32 {
+        self.ext.version()
+    }
+
+    /// 返回指定分区中指定索引的参数值，索引超出 V0 版范围时从扩展参数表读取。
+    pub fn value(&self, area: SeamParamArea, index: usize) -> Option<SeamParamValue> {
+        let n = area.count();
+        if index < n {
+            unsafe { Some(self.v0.values[area.offset() + index]) }
+        } else {
+            self.ext.area(area).get(index - n).copied()
+        }
+    }
+
+    /// 设置指定分区中指定索引的参数值，索引无效时返回 `false`。
+    pub fn set_value(&mut self, area: SeamParamArea, index: usize, value: SeamParamValue) -> bool {
+        let n = area.count();
+        let dst = if index < n {
+            unsafe { self.v0.values.get_mut(area.offset() + index) }
+        } else {
+            self.ext.area_mut(area).get_mut(index - n)
+        };
+        dst.map(|v| *v = value).is_some()
+    }
+
+    /// 返回 AP 分区中指定索引的参数值。
+    pub fn ap(&self, index: usize) -> Option<SeamParamValue> {
+        self.ext.ap.get(index).copied()
+    }
+
+    /// 设置 AP 分区中指定索引的参数值，索引无效时返回 `false`。
+    pub fn set_ap(&mut self, index: usize, value: SeamParamValue) -> bool {
+        self.ext.ap.get_mut(index).map(|v| *v = value).is_some()
+    }
+}
+
+/// 一个代表参数表版本升级的特征。
+pub trait SeamParamsMigration {
+    /// 参数表布局版本。
+    const VERSION: i32;
+
+    /// 将旧版本参数表升级到当前布局，返回是否有升级。
+    ///
+    /// 布局版本记录在扩展参数表中，不占用 V0 版的 `SfVersion`；高于当前布局时返回错误。
+    fn migrate(&mut self) -> Result<bool, SeamProfileError>;
+}
+
+impl SeamParamsMigration for SeamParamsV1 {
+    const VERSION: i32 = 1;
+
+    fn migrate(&mut self) -> Result<bool, SeamProfileError> {
+        let version = self.version();
+        if version > Self::VERSION {
+            return Err(SeamProfileError::UnsupportedVersion(version));
+        }
+        if version == Self::VERSION {
+            return Ok(false);
+        }
+        self.ext = Default::default();
+        self.ext.sf[9] = Self::VERSION.into();
+        Ok(true)
+    }
+}
+
+impl SeamProfile {
+    /// 返回 V1 版参数表，与 V0 版参数表共享前半部分。
+    pub fn v1(&self) -> &SeamParamsV1 {
+        unsafe { &*self.v1_ptr() }
+    }
+
+    pub fn v1_mut(&mut self) -> &mut SeamParamsV1 {
+        unsafe { &mut *self.v1_mut_ptr() }
+    }
+
+    pub fn v1_ptr(&self) -> *const SeamParamsV1 {
+        let base = self as *const Self as *const u8;
+        unsafe { base.add(offset_of!(SeamProfile, v0)) as *const SeamParamsV1 }
+    }
+
+    pub fn v1_mut_ptr(&mut self) -> *mut SeamParamsV1 {
+        let base = self as *mut Self as *mut u8;
+        unsafe { base.add(offset_of!(SeamProfile, v0)) as *mut SeamParamsV1 }
+    }
+
+    /// 根据档案规范及参数表版本将配置升级到当前布局，返回是否有升级。
+    ///
+    /// 缺少档案规范的旧配置视为 V0 版配置并补全档案规范；升级后协调元数据与 SF 区。
+    pub fn migrate(&mut self) -> Result<bool, SeamProfileError> {
+        check_schema(&self.schema, SEAM_PROFILE_SCHEMA)?;
+        let legacy = self.schema.is_empty();
+        if legacy {
+            self.schema = SEAM_PROFILE_SCHEMA.into();
+            self.v1_mut().ext = Default::default();
+        }
+        let upgraded = self.v1_mut().migrate()? || legacy;
+        self.reconcile_meta();
+        Ok(upgraded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SeamParamFlatId;
+
+    #[test]
+    fn test_migrate_v0_profile() {
+        let mut v0 = SeamProfile::new(true, 5);
+        v0.v0.set(SeamParamFlatId::SfVersion, 7);
+        v0.v0.set(SeamParamFlatId::KpAngle1, 30.0f32);
+        let mut json = serde_json::to_value(&v0).unwrap();
+        json.as_object_mut().unwrap().remove("v1");
+        json["schema"] = "".into();
+
+        let mut profile: SeamProfile = serde_json::from_value(json).unwrap();
+        assert_eq!(profile.v1().version(), 0);
+        assert!(profile.migrate().unwrap());
+        assert!(!profile.migrate().unwrap());
+        assert_eq!(profile.schema, SEAM_PROFILE_SCHEMA);
+        assert_eq!(profile.v1().version(), 1);
+        assert_eq!(profile.v0.version(), 7);
+        assert_eq!(
+            profile
+                .v1()
+                .value(SeamParamArea::Kp, 0)
+                .map(|v| unsafe { v.f32_val }),
+            Some(30.0)
+        );
+
+        let ap = SeamParamValue::from_i32(42);
+        assert!(profile.v1_mut().set_value(SeamParamArea::Kp, 59, ap));
+        assert!(!profile.v1_mut().set_value(SeamParamArea::Kp, 60, ap));
+        assert!(profile.v1_mut().set_ap(99, ap));
+        assert_eq!(unsafe { profile.v1_ext.kp[29].i32_val }, 42);
+        assert_eq!(unsafe { profile.v1_ext.ap[99].i32_val }, 42);
+        assert_eq!(profile.v1_ptr() as *const SeamParamsV0, profile.v0_ptr());
+
+        profile.v0.set(SeamParamFlatId::SfVersion, 2);
+        assert!(!profile.migrate().unwrap());
+        profile.v1_ext.sf[9] = 2.into();
+        assert!(matches!(
+            profile.migrate(),
+            Err(SeamProfileError::UnsupportedVersion(2))
+        ));
+
+        let mut json = serde_json::to_value(&profile).unwrap();
+        json["v1"]["values"] = vec![0; 351].into();
+        assert!(serde_json::from_value::<SeamProfile>(json).is_err());
+    }
+}
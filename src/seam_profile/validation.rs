@@ -0,0 +1,308 @@
+use super::{
+    SeamParamArea, SeamParamFlatId, SeamParamType, SeamParamTypedValue, SeamParamXpId,
+    SeamParamsV0, SeamProfile,
+};
+use std::fmt;
+
+/// VP 区中需要满足 `min <= max` 的参数对，仅在两者类型均已确认时检查。
+const VP_MIN_MAX_PAIRS: &[(SeamParamFlatId, SeamParamFlatId)] = &[
+    (SeamParamFlatId::VpAngleMin, SeamParamFlatId::VpAngleMax),
+    (SeamParamFlatId::VpGapMin, SeamParamFlatId::VpGapMax),
+    (SeamParamFlatId::VpDropMin, SeamParamFlatId::VpDropMax),
+    (
+        SeamParamFlatId::VpGrooveAreaMin,
+        SeamParamFlatId::VpGrooveAreaMax,
+    ),
+    (SeamParamFlatId::VpWidth1Min, SeamParamFlatId::VpWidth1Max),
+    (SeamParamFlatId::VpWidth2Min, SeamParamFlatId::VpWidth2Max),
+    (SeamParamFlatId::VpWidth3Min, SeamParamFlatId::VpWidth3Max),
+    (SeamParamFlatId::VpWidth4Min, SeamParamFlatId::VpWidth4Max),
+    (
+        SeamParamFlatId::VpWeldedAngleMin,
+        SeamParamFlatId::VpWeldedAngleMax,
+    ),
+    (
+        SeamParamFlatId::VpWeldedAreaMin,
+        SeamParamFlatId::VpWeldedAreaMax,
+    ),
+    (
+        SeamParamFlatId::VpWeldedLengthMin,
+        SeamParamFlatId::VpWeldedLengthMax,
+    ),
+    (
+        SeamParamFlatId::VpWeldedThicknessMin,
+        SeamParamFlatId::VpWeldedThicknessMax,
+    ),
+    (
+        SeamParamFlatId::VpWeldedWidthMin,
+        SeamParamFlatId::VpWeldedWidthMax,
+    ),
+    (
+        SeamParamFlatId::VpNormalAngleMin,
+        SeamParamFlatId::VpNormalAngleMax,
+    ),
+];
+
+/// 一个代表接头识别参数校验选项的类型。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamValidateOptions {
+    /// 传感器宽度（像素）。
+    pub sensor_width: i32,
+    /// 传感器高度（像素）。
+    pub sensor_height: i32,
+    /// 是否要求设置有效的接头类型。
+    pub require_joint_type: bool,
+}
+
+impl Default for SeamValidateOptions {
+    fn default() -> Self {
+        Self {
+            sensor_width: 8192,
+            sensor_height: 8192,
+            require_joint_type: false,
+        }
+    }
+}
+
+/// 一个代表接头识别参数校验违例的枚举。
+#[derive(Clone, Debug, PartialEq)]
+pub enum SeamParamViolation {
+    /// 参数值超出合法范围或不是有效选项。
+    OutOfRange {
+        id: SeamParamFlatId,
+        value: SeamParamTypedValue,
+    },
+    /// 下限参数大于上限参数。
+    MinGreaterThanMax {
+        min_id: SeamParamFlatId,
+        max_id: SeamParamFlatId,
+        min: SeamParamTypedValue,
+        max: SeamParamTypedValue,
+    },
+    /// 感兴趣区域超出传感器范围。
+    RoiOutOfBounds {
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        sensor_width: i32,
+        sensor_height: i32,
+    },
+    /// 使能位指向保留参数或超出分区范围。
+    ReservedEnabled { area: SeamParamArea, index: usize },
+    /// 接头类型未设置或无法识别。
+    UnknownJointType { value: i32 },
+}
+
+impl fmt::Display for SeamParamViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeamParamViolation::OutOfRange { id, value } => match id.desc() {
+                Some(d) if !d.choices.is_empty() => {
+                    write!(f, "参数 {} 的值 {} 不是有效选项", d.name, value)
+                }
+                Some(d) => write!(
+                    f,
+                    "参数 {} 的值 {} 超出范围 [{}, {}]",
+                    d.name, value, d.min, d.max
+                ),
+                None => write!(f, "参数 {:?} 的值 {} 无效", id, value),
+            },
+            SeamParamViolation::MinGreaterThanMax {
+                min_id,
+                max_id,
+                min,
+                max,
+            } => write!(
+                f,
+                "参数 {} ({}) 大于 {} ({})",
+                min_id.name(),
+                min,
+                max_id.name(),
+                max
+            ),
+            SeamParamViolation::RoiOutOfBounds {
+                x,
+                y,
+                w,
+                h,
+                sensor_width,
+                sensor_height,
+            } => write!(
+                f,
+                "感兴趣区域 ({}, {}, {}, {}) 超出传感器范围 {}x{}",
+                x, y, w, h, sensor_width, sensor_height
+            ),
+            SeamParamViolation::ReservedEnabled { area, index } => {
+                write!(f, "{} 区使能位 {} 指向保留参数", area, index)
+            }
+            SeamParamViolation::UnknownJointType { value } => {
+                write!(f, "接头类型 0x{:04x} 无效", value)
+            }
+        }
+    }
+}
+
+impl SeamParamsV0 {
+    /// 以默认选项校验参数表。
+    pub fn validate(&self) -> Vec<SeamParamViolation> {
+        self.validate_with(&SeamValidateOptions::default())
+    }
+
+    /// 以指定选项校验参数表，返回所有违例。
+    ///
+    /// 类型未确认的参数不参与范围、上下限及感兴趣区域检查。
+    pub fn validate_with(&self, options: &SeamValidateOptions) -> Vec<SeamParamViolation> {
+        let mut violations = Vec::new();
+
+        for desc in super::seam_param_descs() {
+            let value = unsafe { desc.decode(self.values[desc.id as usize]) };
+            if !desc.contains(value) {
+                violations.push(SeamParamViolation::OutOfRange { id: desc.id, value });
+            }
+        }
+
+        for &(min_id, max_id) in VP_MIN_MAX_PAIRS {
+            if let (Some(min), Some(max)) = (self.get_typed(min_id), self.get_typed(max_id)) {
+                if min.as_f64() > max.as_f64() {
+                    violations.push(SeamParamViolation::MinGreaterThanMax {
+                        min_id,
+                        max_id,
+                        min,
+                        max,
+                    });
+                }
+            }
+        }
+
+        let roi = [
+            SeamParamXpId::RoiX,
+            SeamParamXpId::RoiY,
+            SeamParamXpId::RoiW,
+            SeamParamXpId::RoiH,
+        ]
+        .map(|id| match self.get_typed(id) {
+            Some(SeamParamTypedValue::I32(v)) => Some(v),
+            _ => None,
+        });
+        if let [Some(x), Some(y), Some(w), Some(h)] = roi {
+            if x < 0
+                || y < 0
+                || w < 0
+                || h < 0
+                || x as i64 + w as i64 > options.sensor_width as i64
+                || y as i64 + h as i64 > options.sensor_height as i64
+            {
+                violations.push(SeamParamViolation::RoiOutOfBounds {
+                    x,
+                    y,
+                    w,
+                    h,
+                    sensor_width: options.sensor_width,
+                    sensor_height: options.sensor_height,
+                });
+            }
+        }
+
+        for area in SeamParamArea::ALL {
+            for index in self.en_bits(area).iter() {
+                let reserved = area
+                    .flat_id(index)
+                    .desc()
+                    .map_or(true, |desc| desc.reserved);
+                if reserved {
+                    violations.push(SeamParamViolation::ReservedEnabled { area, index });
+                }
+            }
+        }
+
+        if options.require_joint_type && self.typed_joint_type().is_none() {
+            violations.push(SeamParamViolation::UnknownJointType {
+                value: self.joint_type(),
+            });
+        }
+
+        violations
+    }
+
+    /// 返回类型已确认的参数值，未确认类型的参数返回 `None`。
+    fn get_typed<I: Into<SeamParamFlatId>>(&self, id: I) -> Option<SeamParamTypedValue> {
+        let id = id.into();
+        match id.desc()?.ty {
+            SeamParamType::Raw => None,
+            _ => self.get(id),
+        }
+    }
+
+    /// 检查参数表是否通过默认选项校验。
+    pub fn is_consistent(&self) -> bool {
+        self.validate().is_empty()
+    }
+}
+
+impl SeamProfile {
+    /// 以默认选项校验配置。
+    pub fn validate(&self) -> Vec<SeamParamViolation> {
+        self.validate_with(&SeamValidateOptions::default())
+    }
+
+    /// 以指定选项校验配置，返回所有违例。
+    ///
+    /// 未启用的配置槽位保存的是未经设置的参数，不作校验。
+    pub fn validate_with(&self, options: &SeamValidateOptions) -> Vec<SeamParamViolation> {
+        if !self.is_enabled() {
+            return Vec::new();
+        }
+        self.v0.validate_with(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::{SeamParamVpId, SeamProfileError, SeamProfileManager};
+
+    #[test]
+    fn test_validate_v0() {
+        assert!(SeamParamsV0::default().validate().is_empty());
+        assert!(SeamParamsV0::with_defaults().validate().is_empty());
+        assert!(SeamProfile::new(false, 0).validate().is_empty());
+
+        let mut v0 = SeamParamsV0::with_defaults();
+        v0.set(SeamParamVpId::AngleMin, 30.0f32);
+        v0.set(SeamParamVpId::AngleMax, 10.0f32);
+        v0.set(SeamParamXpId::RoiX, 100);
+        v0.set(SeamParamXpId::RoiW, 10000);
+        v0.set(SeamParamFlatId::KpAngle1, f32::NAN);
+        v0.set(SeamParamFlatId::SfJointType, -1);
+        v0.set(SeamParamFlatId::SfXpEn, 1 << 20);
+        let violations = v0.validate();
+        assert_eq!(violations.len(), 3, "{:?}", violations);
+        assert!(violations.contains(&SeamParamViolation::OutOfRange {
+            id: SeamParamFlatId::SfJointType,
+            value: SeamParamTypedValue::I32(-1),
+        }));
+        assert!(violations.contains(&SeamParamViolation::ReservedEnabled {
+            area: SeamParamArea::Xp,
+            index: 20,
+        }));
+    }
+
+    #[test]
+    fn test_reject_invalid_json() {
+        let dir = TestDir::new("validation");
+        let dir = dir.to_str();
+        let mut mgr = SeamProfileManager::new(format!("{}/backup", dir), format!("{}/config", dir));
+        mgr.set_reject_invalid(true);
+        let mut profile = SeamProfile::new(true, 3);
+        profile.v0.set(SeamParamFlatId::KpLength1, f32::INFINITY);
+        let json = serde_json::to_string(&profile).unwrap();
+        let r = mgr.load_profile_from_json_str(&json);
+        assert!(matches!(r, Err(SeamProfileError::Validation { id: 3, .. })));
+        assert!(!mgr.get_profile(3).is_enabled());
+        mgr.set_reject_invalid(false);
+        assert!(mgr.load_profile_from_json_str(&json).is_ok());
+        assert!(mgr.get_profile(3).is_enabled());
+    }
+}
@@ -0,0 +1,315 @@
+use super::{SeamProfileError, SeamProfileEvent, SeamProfileManager};
+use log::{info, warn};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+/// 一个代表外部修改与未保存变更冲突时处理策略的枚举。
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SeamReloadPolicy {
+    /// 以文件为准，丢弃内存中未保存的变更。
+    #[default]
+    PreferFile,
+    /// 以内存为准，保留未保存的变更，下次保存时覆盖文件。
+    PreferMemory,
+}
+
+/// 一个代表配置文件外部修改处理结果的枚举。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeamExternalChange {
+    /// 文件由管理器自身写入或与内存中的配置一致，无需处理。
+    Unchanged,
+    /// 已重新加载文件中的配置。
+    Reloaded,
+    /// 与未保存的变更冲突，保留了内存中的配置。
+    KeptMemory,
+}
+
+/// 一个代表配置目录监视选项的类型。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamProfileWatcherOptions {
+    /// 冲突处理策略。
+    pub policy: SeamReloadPolicy,
+    /// 检查间隔。
+    pub poll_interval: Duration,
+}
+
+impl Default for SeamProfileWatcherOptions {
+    fn default() -> Self {
+        Self {
+            policy: Default::default(),
+            poll_interval: Duration::from_millis(200),
+        }
+    }
+}
+
+/// 返回文件内容的摘要。
+fn text_hash(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl SeamProfileManager {
+    /// 记录管理器写入配置文件的内容摘要，用于区分外部修改。
+    pub(super) fn mark_saved(&self, id: usize, text: &str) {
+        if let Some(hash) = self.saved_hashes.get(id) {
+            hash.store(text_hash(text), Ordering::SeqCst);
+        }
+    }
+
+    /// 指定编号配置最近一次由管理器写入的内容是否为 `text`。
+    pub(super) fn is_saved(&self, id: usize, text: &str) -> bool {
+        self.saved_hashes
+            .get(id)
+            .is_some_and(|hash| hash.load(Ordering::SeqCst) == text_hash(text))
+    }
+
+    /// 处理指定编号配置文件的外部修改。
+    ///
+    /// 文件由管理器自身写入或与内存中的配置一致时忽略；否则校验文件内容，
+    /// 内存中无未保存的变更时重新加载，有变更时按 `policy` 处理冲突。
+    pub fn apply_external_change(
+        &mut self,
+        id: usize,
+        policy: SeamReloadPolicy,
+    ) -> Result<SeamExternalChange, SeamProfileError> {
+        self.try_get_profile(id)?;
+        let path = self.profile_path(id);
+        let text = std::fs::read_to_string(&path)?;
+        if self.is_saved(id, &text) {
+            return Ok(SeamExternalChange::Unchanged);
+        }
+        let hash = text_hash(&text);
+        if self.profile_text(id).is_ok_and(|t| t == text) {
+            self.saved_hashes[id].store(hash, Ordering::SeqCst);
+            return Ok(SeamExternalChange::Unchanged);
+        }
+
+        let profile = match self.parse_profile(&text) {
+            Ok(profile) => profile,
+            Err(err) => {
+                warn!("配置文件 {} 在外部被修改，但内容无效：{}", path, err);
+                self.emit(SeamProfileEvent::ExternalRejected {
+                    profile_id: id,
+                    reason: err.to_string(),
+                });
+                return Err(err);
+            }
+        };
+        if self.profiles[id].commits() > 0 {
+            warn!(
+                "配置 #{} 在外部被修改，与未保存的变更冲突，按 {:?} 处理",
+                id, policy
+            );
+            self.emit(SeamProfileEvent::ExternalConflict {
+                profile_id: id,
+                policy,
+            });
+            if policy == SeamReloadPolicy::PreferMemory {
+                return Ok(SeamExternalChange::KeptMemory);
+            }
+        }
+
+        self.saved_hashes[id].store(hash, Ordering::SeqCst);
+        self.put_profile(id, &profile);
+        self.profiles[id].flush();
+        info!("配置 #{} 已从外部修改的文件 {} 重新加载", id, path);
+        self.emit(SeamProfileEvent::ProfilesReloaded { ids: vec![id] });
+        Ok(SeamExternalChange::Reloaded)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod watcher {
+    use super::super::archive::profile_id_from_name;
+    use super::super::{SeamProfileManager, SeamProfileManagerLock};
+    use super::SeamProfileWatcherOptions;
+    use inotify::{Inotify, WatchMask};
+    use log::{error, info};
+    use std::collections::BTreeSet;
+    use std::io;
+    use std::path::Path;
+    use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+
+    /// 一个代表配置目录监视线程的类型。
+    ///
+    /// 配置文件在外部被写入或移入配置目录时，重新加载并校验对应的配置，
+    /// 销毁时自动停止监视线程。
+    pub struct SeamProfileWatcher {
+        stop_tx: Option<Sender<()>>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl SeamProfileWatcher {
+        /// 为指定的配置管理器启动监视线程。
+        pub fn spawn(
+            mgr: Arc<SeamProfileManagerLock>,
+            options: SeamProfileWatcherOptions,
+        ) -> io::Result<Self> {
+            let config_dir = SeamProfileManager::lock_blocking(&mgr).config_dir.clone();
+            let mut inotify = Inotify::init()?;
+            inotify
+                .watches()
+                .add(&config_dir, WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO)?;
+            info!("开始监视配置目录 {}", config_dir);
+
+            let (stop_tx, stop_rx) = mpsc::channel::<()>();
+            let handle = std::thread::Builder::new()
+                .name("seam-profile-watcher".into())
+                .spawn(move || {
+                    let mut buffer = [0u8; 4096];
+                    while let Err(RecvTimeoutError::Timeout) =
+                        stop_rx.recv_timeout(options.poll_interval)
+                    {
+                        let ids = match read_changed_ids(&mut inotify, &mut buffer) {
+                            Ok(ids) => ids,
+                            Err(err) => {
+                                error!("读取配置目录 {} 的变更失败：{}", config_dir, err);
+                                break;
+                            }
+                        };
+                        if ids.is_empty() {
+                            continue;
+                        }
+                        let mut mgr = SeamProfileManager::lock_blocking(&mgr);
+                        for id in ids {
+                            if let Err(err) = mgr.apply_external_change(id, options.policy) {
+                                error!("处理配置 #{} 的外部修改失败：{}", id, err);
+                            }
+                        }
+                    }
+                })?;
+            Ok(Self {
+                stop_tx: Some(stop_tx),
+                handle: Some(handle),
+            })
+        }
+
+        /// 停止监视线程并等待其退出。
+        pub fn stop(mut self) {
+            self.shutdown();
+        }
+
+        fn shutdown(&mut self) {
+            drop(self.stop_tx.take());
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+
+    impl Drop for SeamProfileWatcher {
+        fn drop(&mut self) {
+            self.shutdown();
+        }
+    }
+
+    /// 读取所有待处理的事件，返回发生变更的配置编号。
+    fn read_changed_ids(inotify: &mut Inotify, buffer: &mut [u8]) -> io::Result<BTreeSet<usize>> {
+        let mut ids = BTreeSet::new();
+        loop {
+            match inotify.read_events(buffer) {
+                Ok(events) => {
+                    ids.extend(events.filter_map(|e| profile_id_from_name(Path::new(e.name?))))
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(ids),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use watcher::SeamProfileWatcher;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+    use crate::SeamProfile;
+
+    fn write_external(mgr: &SeamProfileManager, id: usize, name: &str) {
+        let mut profile = SeamProfile::new(true, id as i32);
+        profile.set_name(name);
+        let text = serde_json::to_string(&profile).unwrap();
+        std::fs::write(mgr.profile_path(id), text).unwrap();
+    }
+
+    #[test]
+    fn test_external_change() {
+        let dir = TestDir::new("watch");
+        let dir = dir.to_str();
+        let mut mgr =
+            SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir))
+                .unwrap();
+        mgr.load_all_profiles().unwrap();
+        let policy = SeamReloadPolicy::PreferFile;
+        assert_eq!(
+            mgr.apply_external_change(1, policy).unwrap(),
+            SeamExternalChange::Unchanged
+        );
+
+        write_external(&mgr, 1, "External");
+        assert_eq!(
+            mgr.apply_external_change(1, policy).unwrap(),
+            SeamExternalChange::Reloaded
+        );
+        assert_eq!(mgr.get_profile(1).name(), "External");
+        assert_eq!(mgr.get_profile(1).commits(), 0);
+
+        mgr.rename_profile(1, "Local").unwrap();
+        write_external(&mgr, 1, "External2");
+        assert_eq!(
+            mgr.apply_external_change(1, SeamReloadPolicy::PreferMemory)
+                .unwrap(),
+            SeamExternalChange::KeptMemory
+        );
+        assert_eq!(mgr.get_profile(1).name(), "Local");
+        mgr.flush().unwrap();
+        assert_eq!(
+            mgr.apply_external_change(1, policy).unwrap(),
+            SeamExternalChange::Unchanged
+        );
+
+        std::fs::write(mgr.profile_path(2), "{").unwrap();
+        assert!(mgr.apply_external_change(2, policy).is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_watcher() {
+        use super::super::SeamProfileManagerLock;
+        use std::sync::Arc;
+
+        let dir = TestDir::new("watcher");
+        let dir = dir.to_str();
+        let mgr = Arc::new(SeamProfileManagerLock::new(
+            SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir))
+                .unwrap(),
+        ));
+        let options = SeamProfileWatcherOptions {
+            poll_interval: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let watcher = SeamProfileWatcher::spawn(mgr.clone(), options).unwrap();
+        write_external(&SeamProfileManager::lock_blocking(&mgr), 6, "Copied");
+        let mut reloaded = false;
+        for _ in 0..200 {
+            std::thread::sleep(Duration::from_millis(10));
+            if SeamProfileManager::lock_blocking(&mgr)
+                .get_profile(6)
+                .name()
+                == "Copied"
+            {
+                reloaded = true;
+                break;
+            }
+        }
+        watcher.stop();
+        assert!(reloaded);
+    }
+}
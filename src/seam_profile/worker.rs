@@ -0,0 +1,280 @@
+use super::{SeamProfileManager, SeamProfileManagerLock};
+use log::{debug, error};
+use std::io;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// 一个代表配置自动保存及备份选项的类型。
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeamProfileWorkerOptions {
+    /// 最后一次变更后的静默时长，超过后才保存到文件。
+    pub flush_quiet: Duration,
+    /// 自动备份间隔，为 `None` 时不自动备份。
+    pub backup_interval: Option<Duration>,
+    /// 检查间隔。
+    pub poll_interval: Duration,
+}
+
+impl Default for SeamProfileWorkerOptions {
+    fn default() -> Self {
+        Self {
+            flush_quiet: Duration::from_secs(2),
+            backup_interval: Some(Duration::from_secs(600)),
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+}
+
+/// 工作线程的调度状态。
+struct Schedule {
+    options: SeamProfileWorkerOptions,
+    last_backup: Instant,
+}
+
+impl Schedule {
+    fn new(options: SeamProfileWorkerOptions) -> Self {
+        Self {
+            options,
+            last_backup: Instant::now(),
+        }
+    }
+
+    /// 是否需要自动保存。
+    fn flush_due(&self, mgr: &SeamProfileManager) -> bool {
+        let due = mgr.commits() > 0 && mgr.all_modified().elapsed() >= self.options.flush_quiet;
+        if due {
+            debug!("配置已静默 {:?}，自动保存", self.options.flush_quiet);
+        }
+        due
+    }
+
+    /// 是否需要自动备份，需要时重新计时。
+    fn backup_due(&mut self) -> bool {
+        match self.options.backup_interval {
+            Some(interval) if self.last_backup.elapsed() >= interval => {
+                self.last_backup = Instant::now();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 执行一次检查，按需保存及备份。
+    ///
+    /// 仅在检查、生成快照及替换文件时持有管理器锁，写入文件期间不持有管理器锁。
+    fn tick(&mut self, mgr: &SeamProfileManagerLock) {
+        let flush = {
+            let mgr = mgr.lock_blocking();
+            mgr.republish();
+            self.flush_due(&mgr)
+        };
+        if flush {
+            if let Err(err) = SeamProfileManager::flush_blocking(mgr) {
+                error!("自动保存配置失败：{}", err);
+            }
+        }
+        if self.backup_due() {
+            let snapshot = mgr.lock_blocking().backup_snapshot();
+            if let Err(err) = snapshot.and_then(|snapshot| snapshot.write()) {
+                error!("自动备份配置失败：{}", err);
+            }
+        }
+    }
+
+    /// 退出前保存所有未保存的变更。
+    fn finish(&mut self, mgr: &SeamProfileManagerLock) {
+        if let Err(err) = SeamProfileManager::flush_blocking(mgr) {
+            error!("退出时保存配置失败：{}", err);
+        }
+    }
+}
+
+/// 一个代表配置自动保存及备份工作线程的类型。
+///
+/// 销毁时自动停止工作线程，并在停止前保存所有未保存的变更。
+pub struct SeamProfileWorker {
+    stop_tx: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SeamProfileWorker {
+    /// 为指定的配置管理器启动工作线程。
+    pub fn spawn(
+        mgr: Arc<SeamProfileManagerLock>,
+        options: SeamProfileWorkerOptions,
+    ) -> io::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let handle = std::thread::Builder::new()
+            .name("seam-profile-worker".into())
+            .spawn(move || {
+                let mut schedule = Schedule::new(options);
+                while let Err(RecvTimeoutError::Timeout) =
+                    stop_rx.recv_timeout(options.poll_interval)
+                {
+                    schedule.tick(&mgr);
+                }
+                schedule.finish(&mgr);
+            })?;
+        Ok(Self {
+            stop_tx: Some(stop_tx),
+            handle: Some(handle),
+        })
+    }
+
+    /// 停止工作线程并等待其退出。
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        drop(self.stop_tx.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for SeamProfileWorker {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// 一个代表配置自动保存及备份异步任务的类型。
+///
+/// 保存及备份期间不持有管理器锁，@see [`SeamProfileManager::flush_async`]。
+#[cfg(feature = "async")]
+pub struct SeamProfileAsyncWorker {
+    stop_tx: tokio::sync::oneshot::Sender<()>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+#[cfg(feature = "async")]
+impl SeamProfileAsyncWorker {
+    /// 在当前 Tokio 运行时中为指定的配置管理器启动异步任务。
+    pub fn spawn(mgr: Arc<SeamProfileManagerLock>, options: SeamProfileWorkerOptions) -> Self {
+        let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            let mut schedule = Schedule::new(options);
+            let start = tokio::time::Instant::now() + options.poll_interval;
+            let mut interval = tokio::time::interval_at(start, options.poll_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = &mut stop_rx => break,
+                    _ = interval.tick() => {}
+                }
+                let flush = {
+                    let mgr = SeamProfileManager::lock_async(&mgr).await;
+                    mgr.republish();
+                    schedule.flush_due(&mgr)
+                };
+                if flush {
+                    if let Err(err) = SeamProfileManager::flush_async(&mgr).await {
+                        error!("自动保存配置失败：{}", err);
+                    }
+                }
+                if schedule.backup_due() {
+                    if let Err(err) = SeamProfileManager::auto_backup_async(&mgr).await {
+                        error!("自动备份配置失败：{}", err);
+                    }
+                }
+            }
+            if let Err(err) = SeamProfileManager::flush_async(&mgr).await {
+                error!("退出时保存配置失败：{}", err);
+            }
+        });
+        Self { stop_tx, handle }
+    }
+
+    /// 停止异步任务并等待其退出。
+    pub async fn stop(self) {
+        let _ = self.stop_tx.send(());
+        let _ = self.handle.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_worker_tick() {
+        let dir = TestDir::new("worker");
+        let dir = dir.to_str();
+        let mgr = SeamProfileManagerLock::new(SeamProfileManager::new(
+            format!("{}/backup", dir),
+            format!("{}/config", dir),
+        ));
+        let options = SeamProfileWorkerOptions {
+            flush_quiet: Duration::from_secs(2),
+            backup_interval: Some(Duration::from_secs(600)),
+            poll_interval: Duration::from_millis(500),
+        };
+        let mut schedule = Schedule::new(options);
+        let path = {
+            let mut mgr = mgr.lock_blocking();
+            mgr.load_all_profiles().unwrap();
+            mgr.set_current_profile_name("Worker");
+            mgr.profile_path(0)
+        };
+
+        schedule.tick(&mgr);
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("Worker"));
+        {
+            let mgr = mgr.lock_blocking();
+            let modified = mgr.all_modified();
+            modified.set_millis(modified.as_millis() - 2000);
+        }
+        schedule.tick(&mgr);
+        assert!(std::fs::read_to_string(&path).unwrap().contains("Worker"));
+        assert_eq!(mgr.lock_blocking().commits(), 0);
+        assert!(mgr.lock_blocking().list_backups().unwrap().is_empty());
+
+        schedule.last_backup -= Duration::from_secs(600);
+        schedule.tick(&mgr);
+        assert_eq!(mgr.lock_blocking().list_backups().unwrap().len(), 1);
+
+        mgr.lock_blocking().enable_profile(1);
+        schedule.finish(&mgr);
+        assert_eq!(mgr.lock_blocking().commits(), 0);
+    }
+
+    #[test]
+    fn test_worker_stop() {
+        let dir = TestDir::new("worker-stop");
+        let dir = dir.to_str();
+        let mgr = Arc::new(SeamProfileManagerLock::new(SeamProfileManager::new(
+            format!("{}/backup", dir),
+            format!("{}/config", dir),
+        )));
+        let worker = SeamProfileWorker::spawn(mgr.clone(), Default::default()).unwrap();
+        SeamProfileManager::lock_blocking(&mgr).enable_profile(1);
+        worker.stop();
+        assert_eq!(SeamProfileManager::lock_blocking(&mgr).commits(), 0);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_worker_flush() {
+        let dir = TestDir::new("async-worker");
+        let dir = dir.to_str();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let mgr = Arc::new(SeamProfileManagerLock::new(SeamProfileManager::new(
+                format!("{}/backup", dir),
+                format!("{}/config", dir),
+            )));
+            let worker = SeamProfileAsyncWorker::spawn(mgr.clone(), Default::default());
+            SeamProfileManager::lock_async(&mgr).await.enable_profile(3);
+            worker.stop().await;
+            assert_eq!(SeamProfileManager::lock_async(&mgr).await.commits(), 0);
+        });
+    }
+}
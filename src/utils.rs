@@ -1,13 +1,19 @@
+mod atomic_write;
 mod circular_file;
 mod file_digest;
 mod fps_counter;
 mod sysfs;
 mod system_temperature;
+#[cfg(test)]
+mod test_dir;
 mod timestamp;
 
+pub use atomic_write::write_atomic;
 pub use circular_file::{CircularFile, CircularWrite};
 pub use file_digest::FileDigest;
 pub use fps_counter::FpsCounter;
 pub use sysfs::Sysfs;
 pub use system_temperature::SystemTemperature;
+#[cfg(test)]
+pub(crate) use test_dir::TestDir;
 pub use timestamp::Timestamp;
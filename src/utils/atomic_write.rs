@@ -0,0 +1,57 @@
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// 以原子方式写入文件。
+///
+/// 数据先写入同目录下的临时文件并同步到磁盘，再重命名覆盖目标文件，
+/// 掉电时目标文件要么保持旧内容，要么为完整的新内容。
+pub fn write_atomic<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
+    let path = path.as_ref();
+    let tmp_path = tmp_path(path);
+
+    let r = (|| {
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+    })();
+    if r.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+        return r;
+    }
+
+    // 同步目录项，确保重命名操作落盘。
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// 返回目标文件同目录下的临时文件路径。
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut tmp_name = OsString::from(".");
+    tmp_name.push(path.file_name().unwrap_or_default());
+    tmp_name.push(".tmp");
+    path.with_file_name(tmp_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDir;
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = TestDir::new("atomic-write");
+        fs::create_dir_all(dir.path()).unwrap();
+        let path = dir.path().join("test_write_atomic.txt");
+        write_atomic(&path, b"Hello").unwrap();
+        write_atomic(&path, b"Hello, AtomicWrite").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Hello, AtomicWrite");
+        assert!(!tmp_path(&path).exists());
+    }
+}
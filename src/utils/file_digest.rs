@@ -1,4 +1,5 @@
 use sha2::{Digest, Sha256};
+use std::fmt;
 use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
@@ -26,3 +27,12 @@ impl FileDigest {
         })
     }
 }
+
+impl fmt::Display for FileDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in &self.digest {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
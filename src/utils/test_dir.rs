@@ -0,0 +1,38 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// 临时目录序号，用于区分同一进程中的多个测试。
+static TEST_DIR_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// 一个代表测试用临时目录的类型。
+///
+/// 目录名包含进程号及序号，并行执行的测试互不影响；目录不会自动创建，销毁时删除。
+pub(crate) struct TestDir(PathBuf);
+
+impl TestDir {
+    pub fn new(name: &str) -> Self {
+        let seq = TEST_DIR_SEQ.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "fv-common-test-{}-{}-{}",
+            name,
+            std::process::id(),
+            seq
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    pub fn to_str(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
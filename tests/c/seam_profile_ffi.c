@@ -0,0 +1,204 @@
+/// 以 C 语言调用 seam_profile.h 中声明的所有函数。
+///
+/// 用法：seam_profile_ffi <目录> <配置编号>，备份及配置分别位于目录下的 backup 及 config 中，
+/// 该编号的配置文件中 XP 区曝光时间应为 1234.5。
+#include <fv/seam_profile.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#define CHECK(expr)                                                            \
+    do                                                                         \
+    {                                                                          \
+        if (!(expr))                                                           \
+        {                                                                      \
+            fprintf(stderr, "%s:%d: 检查失败：%s\n", __FILE__, __LINE__,     \
+                    #expr);                                                    \
+            return 1;                                                          \
+        }                                                                      \
+    } while (0)
+
+static int check_values(FvSeamParamsV0* spa)
+{
+    fv_spa_v0_xpv(spa)[FV_SPA_XP_LASER_STRENGTH].i32 = 80;
+    CHECK(fv_spa_v0_xp_i32(spa, FV_SPA_XP_LASER_STRENGTH) == 80);
+    CHECK(fv_spa_v0_i32(spa, FV_SPA_F_XP_LASER_STRENGTH) == 80);
+
+    fv_spa_v0_kpv(spa)[FV_SPA_KP_ANGLE1].f32 = 45.0f;
+    CHECK(fv_spa_v0_kp_f32(spa, FV_SPA_KP_ANGLE1) == 45.0f);
+    CHECK(fv_spa_v0_kp_i32(spa, FV_SPA_KP_ANGLE1) == spa->parts.kp[0].i32);
+
+    fv_spa_v0_opv(spa)[FV_SPA_OP_DIRECTION].i32 = 1;
+    CHECK(fv_spa_v0_op_i32(spa, FV_SPA_OP_DIRECTION) == 1);
+    CHECK(fv_spa_v0_op_f32(spa, FV_SPA_OP_DIRECTION) == spa->parts.op[1].f32);
+
+    fv_spa_v0_vpv(spa)[FV_SPA_VP_ANGLE_MIN].f32 = 30.0f;
+    CHECK(fv_spa_v0_vp_f32(spa, FV_SPA_VP_ANGLE_MIN) == 30.0f);
+    CHECK(fv_spa_v0_vp_i32(spa, FV_SPA_VP_ANGLE_MIN) == spa->parts.vp[0].i32);
+
+    fv_spa_v0_ocv(spa)[0].f32 = 2.5f;
+    CHECK(fv_spa_v0_oc_f32(spa, 0) == 2.5f);
+    CHECK(fv_spa_v0_oc_i32(spa, 0) == spa->parts.oc[0].i32);
+
+    fv_spa_v0_sfv(spa)[FV_SPA_SF_JOINT_TYPE].i32 = 0x0301;
+    CHECK(fv_spa_v0_sf_i32(spa, FV_SPA_SF_JOINT_TYPE) == 0x0301);
+    CHECK(fv_spa_v0_sf_f32(spa, FV_SPA_SF_JOINT_TYPE) == spa->parts.sf[0].f32);
+    CHECK(fv_spa_v0_jtma(spa) == 3);
+    CHECK(fv_spa_v0_jtmi(spa) == 1);
+    CHECK(fv_spa_v0_f32(spa, FV_SPA_F_XP_LASER_STRENGTH) == spa->values[5].f32);
+    return 0;
+}
+
+static int check_enables(FvSeamParamsV0* spa)
+{
+    fv_spa_v0_set_xp_en(spa, FV_SPA_XP_EXPOSURE_TIME, 1);
+    CHECK(fv_spa_v0_xp_en(spa, FV_SPA_XP_EXPOSURE_TIME) != 0);
+    fv_spa_v0_set_kp_en(spa, FV_SPA_KP_ANGLE1, 1);
+    CHECK(fv_spa_v0_kp_en(spa, FV_SPA_KP_ANGLE1) != 0);
+    fv_spa_v0_set_kp_en(spa, FV_SPA_KP_ANGLE1, 0);
+    CHECK(fv_spa_v0_kp_en(spa, FV_SPA_KP_ANGLE1) == 0);
+
+    fv_spa_v0_set_op_en(spa, (FvSeamParamOpId)45, 1);
+    CHECK(fv_spa_v0_op_en(spa, (FvSeamParamOpId)45) == 1);
+    CHECK(fv_spa_v0_sf_i32(spa, FV_SPA_SF_OP_EN2) == 1 << 15);
+    fv_spa_v0_set_vp_en(spa, FV_SPA_VP_ANGLE_MIN, 1);
+    CHECK(fv_spa_v0_vp_en(spa, FV_SPA_VP_ANGLE_MIN) == 1);
+    fv_spa_v0_set_oc_en(spa, (FvSeamParamOcId)59, 1);
+    CHECK(fv_spa_v0_oc_en(spa, (FvSeamParamOcId)59) == 1);
+    CHECK(fv_spa_v0_sf_i32(spa, FV_SPA_SF_OC_EN2) == 1 << 29);
+    return 0;
+}
+
+static int check_snapshots(int32_t id)
+{
+    FvSeamProfile* lease = fv_spm_acquire_cur_profile();
+    CHECK(lease != NULL && lease != fv_spm_cur_profile());
+    CHECK(lease->id == 0 && (void*)fv_spr_v1(lease) == (void*)lease->v0);
+    CHECK(fv_spa_v0_xp_f32(fv_spr_v0(lease), FV_SPA_XP_EXPOSURE_TIME) == 2000.0f);
+    fv_spr_v0(lease)->parts.xp[FV_SPA_XP_EXPOSURE_TIME].f32 = 1.0f;
+    CHECK(fv_spa_v0_xp_f32(fv_spa_v0_cur(), FV_SPA_XP_EXPOSURE_TIME) == 2000.0f);
+    fv_spm_release(lease);
+    fv_spm_release(NULL);
+
+    FvSeamParamsV0 v0;
+    uint64_t generation = fv_spm_cur_generation();
+    CHECK(fv_spm_read_cur_v0(NULL) == -1);
+    CHECK(fv_spm_read_cur_v0(&v0) == 0);
+    CHECK(v0.parts.xp[FV_SPA_XP_EXPOSURE_TIME].f32 == 2000.0f);
+
+    FvSeamParamsV1 v1;
+    CHECK(fv_spm_switch_profile(id) == 0);
+    CHECK(fv_spm_cur_generation() > generation);
+    CHECK(fv_spm_read_cur_v1(&v1) == id);
+    CHECK(v1.v0.parts.xp[FV_SPA_XP_EXPOSURE_TIME].f32 == 1234.5f);
+    return 0;
+}
+
+static int check_instances(const char* root, int32_t id)
+{
+    CHECK(fv_spm_init_global("/tmp", "/tmp") == -1);
+    CHECK(fv_spm_global() == fv_spm_global());
+    CHECK(fv_spmh_cur_profile(fv_spm_global()) == fv_spm_cur_profile());
+    CHECK(fv_spmh_cur_profile(NULL) == NULL);
+    CHECK(fv_spmh_switch_profile(NULL, 0) == -1);
+    CHECK(fv_spm_open("ffi-head2") == NULL);
+
+    char dir[4096];
+    snprintf(dir, sizeof(dir), "%s/head2", root);
+    FvSeamProfileManager* head2 = fv_spm_create("ffi-head2", dir, dir);
+    CHECK(head2 != NULL);
+    CHECK(fv_spm_create("ffi-head2", dir, dir) == NULL);
+    FvSeamProfileManager* other = fv_spm_open("ffi-head2");
+    CHECK(other != NULL);
+
+    uint64_t generation = fv_spmh_cur_generation(head2);
+    CHECK(fv_spmh_switch_profile(head2, 3) == 0);
+    CHECK(fv_spmh_cur_generation(other) > generation);
+    CHECK(fv_spmh_cur_profile_id(other) == 3);
+    CHECK(fv_spm_cur_profile_id() == id);
+    CHECK(fv_spmh_set_cur_profile_ptr(head2, fv_spm_cur_profile()) == -1);
+    CHECK(fv_spmh_set_cur_profile_ptr(head2, fv_spmh_profile(head2, 4)) == 0);
+
+    FvSeamParamsV0 v0;
+    CHECK(fv_spmh_read_cur_v0(other, &v0) == 4);
+    FvSeamProfile* lease = fv_spmh_acquire_cur_profile(head2);
+    CHECK(lease != NULL && lease->id == 4);
+    fv_spm_release(lease);
+
+    CHECK(fv_spm_remove("ffi-head2") == 0);
+    CHECK(fv_spm_remove("ffi-head2") == -1);
+    CHECK(fv_spmh_cur_profile_id(head2) == 4);
+    fv_spm_close(head2);
+    fv_spm_close(other);
+    fv_spm_close(fv_spm_global());
+    fv_spm_close(NULL);
+    return 0;
+}
+
+int main(int argc, char* argv[])
+{
+    CHECK(argc == 3);
+    const char* root = argv[1];
+    int32_t id = atoi(argv[2]);
+
+    char backup_dir[4096];
+    char config_dir[4096];
+    snprintf(backup_dir, sizeof(backup_dir), "%s/backup", root);
+    snprintf(config_dir, sizeof(config_dir), "%s/config", root);
+    CHECK(fv_spm_init_global(NULL, config_dir) == -1);
+    CHECK(fv_spm_init_global(backup_dir, config_dir) == 0);
+
+    CHECK(sizeof(FvSeamProfile) == 2 * sizeof(int32_t) + 2 * sizeof(void*));
+    FvSeamProfile* cur = fv_spm_cur_profile();
+    CHECK(cur != NULL);
+    CHECK(fv_spm_cur_profile_id() == 0);
+    CHECK(fv_spm_profile(0) == cur);
+    CHECK(fv_spm_profile(-1) == NULL);
+    CHECK(fv_spm_profile(1 << 20) == NULL);
+
+    FvSeamParamsV0* spa = fv_spr_v0(cur);
+    CHECK(spa == fv_spa_v0_cur());
+    CHECK(fv_spr_v1(cur) == fv_spa_v1_cur());
+    CHECK((void*)fv_spr_v1(cur) == (void*)spa);
+    CHECK(fv_spa_v0_version(spa) == 0);
+    CHECK(fv_spr_v1(cur)->ext.sf[FV_SPA_V1_SF_NUM - FV_SPA_V0_SF_NUM - 1].i32 == 1);
+    CHECK(check_values(spa) == 0);
+    CHECK(check_enables(spa) == 0);
+
+    CHECK(fv_spm_load_profile(-1) == -1);
+    CHECK(fv_spm_load_profile(id) == 0);
+    FvSeamProfile* spr = fv_spm_profile(id);
+    CHECK(spr != NULL && spr->id == id && spr->enabled);
+    CHECK(fv_spa_v0_xp_f32(fv_spr_v0(spr), FV_SPA_XP_EXPOSURE_TIME) == 1234.5f);
+
+    CHECK(fv_spm_switch_profile(-1) == -1);
+    CHECK(fv_spm_set_cur_profile_ptr(NULL) == -1);
+    CHECK(fv_spm_set_cur_profile_ptr(spr) == 0);
+    CHECK(fv_spm_cur_profile_id() == id);
+    CHECK(fv_spm_cur_profile() == spr);
+
+    FvSeamParamsV0 local = *fv_spr_v0(spr);
+    FvSeamProfile copy = *spr;
+    copy.v0 = &local;
+    CHECK(fv_spm_set_cur_profile_ptr(&copy) == -1);
+
+    CHECK(fv_spm_switch_profile(0) == 0);
+    local.parts.xp[FV_SPA_XP_EXPOSURE_TIME].f32 = 2000.0f;
+    CHECK(fv_spm_fill_cur_profile(NULL) == -1);
+    CHECK(fv_spm_fill_cur_profile(&copy) == 0);
+    CHECK(fv_spm_cur_profile_id() == 0);
+    CHECK(fv_spa_v0_xp_f32(fv_spa_v0_cur(), FV_SPA_XP_EXPOSURE_TIME) == 2000.0f);
+    CHECK(fv_spa_v0_xp_f32(fv_spr_v0(spr), FV_SPA_XP_EXPOSURE_TIME) == 1234.5f);
+
+    FvSeamParamsV1 full = *fv_spa_v1_cur();
+    full.ext.ap[0].i32 = 7;
+    CHECK(fv_spm_fill_cur_profile_v1(NULL) == -1);
+    CHECK(fv_spm_fill_cur_profile_v1(&full) == 0);
+    CHECK(fv_spa_v1_cur()->ext.ap[0].i32 == 7);
+    CHECK(fv_spm_fill_cur_profile(&copy) == 0);
+    CHECK(fv_spa_v1_cur()->ext.ap[0].i32 == 7);
+    CHECK(check_snapshots(id) == 0);
+    CHECK(check_instances(root, id) == 0);
+
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,69 @@
+//! 以 C 程序测试 `fv/seam_profile.h` 中声明的接口。
+#![cfg(target_os = "linux")]
+
+use fv_common::{SeamParamFlatId, SeamProfileManager};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 返回与本测试一同构建的静态库。
+fn staticlib() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    std::fs::read_dir(deps)
+        .unwrap()
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            let name = p.file_name().unwrap().to_string_lossy();
+            name.starts_with("libfv_common-") && name.ends_with(".a")
+        })
+        .max_by_key(|p| p.metadata().and_then(|m| m.modified()).ok())
+        .expect("未找到 libfv_common 静态库")
+}
+
+#[test]
+fn test_seam_profile_ffi() {
+    let id = 7;
+    let dir = std::env::temp_dir().join(format!(
+        "fv-common-test-seam-profile-ffi-{}.d",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    let dir = dir.to_str().unwrap();
+    let mut mgr =
+        SeamProfileManager::try_new(format!("{}/backup", dir), format!("{}/config", dir)).unwrap();
+    mgr.enable_profile(id);
+    mgr.get_profile_mut(id)
+        .set_v0_value_f32(SeamParamFlatId::XpExposureTime, 1234.5);
+    mgr.save_profile(id).unwrap();
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out = std::env::temp_dir().join(format!(
+        "fv-common-test-seam-profile-ffi-{}",
+        std::process::id()
+    ));
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .args(["-std=c99", "-Wall", "-Werror", "-o"])
+        .arg(&out)
+        .arg("-I")
+        .arg(root.join("fv-common-sys/include"))
+        .arg(root.join("tests/c/seam_profile_ffi.c"))
+        .arg(staticlib())
+        .args(["-lpthread", "-ldl", "-lm", "-lrt"])
+        .status()
+        .expect("无法启动 C 编译器");
+    assert!(status.success(), "编译 C 测试程序失败");
+
+    let output = Command::new(&out)
+        .arg(dir)
+        .arg(id.to_string())
+        .output()
+        .unwrap();
+    let _ = std::fs::remove_file(&out);
+    let _ = std::fs::remove_dir_all(dir);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+}